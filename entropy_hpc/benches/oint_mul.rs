@@ -0,0 +1,26 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use entropy_hpc::types::oint::MulStrategy;
+use entropy_hpc::OInt;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+fn bench_oint_mul_strategies(c: &mut Criterion) {
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    let mut r = || rng.gen_range(-100..100);
+    let values: Vec<OInt> = (0..1000)
+        .map(|_| OInt::new(r(), r(), r(), r(), r(), r(), r(), r()))
+        .collect();
+
+    for (name, strategy) in [("table", MulStrategy::Table), ("cayley_dickson", MulStrategy::CayleyDickson)] {
+        c.bench_function(&format!("oint_mul_{}", name), |b| {
+            b.iter(|| {
+                for pair in values.windows(2) {
+                    black_box(pair[0].mul_with(pair[1], strategy));
+                }
+            })
+        });
+    }
+}
+
+criterion_group!(benches, bench_oint_mul_strategies);
+criterion_main!(benches);
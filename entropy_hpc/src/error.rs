@@ -0,0 +1,135 @@
+// src/error.rs
+//
+// Display and std::error::Error for the per-type errors, plus one error type
+// for code that mixes the integer rings
+
+use crate::types::cint::CIntError;
+use crate::types::eint::EIntError;
+use crate::types::hint::HIntError;
+use crate::types::oint::OIntError;
+use core::fmt;
+
+// Wraps the per-type errors, which the methods keep returning, so that `?`
+// can propagate any of them from a function returning EntropyError
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntropyError {
+    CInt(CIntError),
+    EInt(EIntError),
+    HInt(HIntError),
+    OInt(OIntError),
+}
+
+impl From<CIntError> for EntropyError {
+    fn from(e: CIntError) -> Self {
+        EntropyError::CInt(e)
+    }
+}
+
+impl From<EIntError> for EntropyError {
+    fn from(e: EIntError) -> Self {
+        EntropyError::EInt(e)
+    }
+}
+
+impl From<HIntError> for EntropyError {
+    fn from(e: HIntError) -> Self {
+        EntropyError::HInt(e)
+    }
+}
+
+impl From<OIntError> for EntropyError {
+    fn from(e: OIntError) -> Self {
+        EntropyError::OInt(e)
+    }
+}
+
+impl fmt::Display for CIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CIntError::Overflow => "arithmetic overflow",
+            CIntError::DivisionByZero => "division by zero",
+            CIntError::NotDivisible => "not exactly divisible",
+            CIntError::NoInverse => "element has no inverse",
+            CIntError::NotAUnit => "not a unit",
+            CIntError::InvalidFormat => "invalid format",
+        })
+    }
+}
+
+impl fmt::Display for EIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            EIntError::Overflow => "arithmetic overflow",
+            EIntError::DivisionByZero => "division by zero",
+            EIntError::NotDivisible => "not exactly divisible",
+            EIntError::NoInverse => "element has no inverse",
+        })
+    }
+}
+
+impl fmt::Display for HIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HIntError::Overflow => "arithmetic overflow",
+            HIntError::DivisionByZero => "division by zero",
+            HIntError::NotDivisible => "not exactly divisible",
+            HIntError::NoInverse => "element has no inverse",
+            HIntError::NotAUnit => "not a unit",
+            HIntError::InvalidHalfInteger => "components must be all integers or all half-integers",
+            HIntError::InvalidFormat => "invalid format",
+            HIntError::NotInSubalgebra => "not in the Gaussian subring",
+        })
+    }
+}
+
+impl fmt::Display for OIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            OIntError::Overflow => "arithmetic overflow",
+            OIntError::DivisionByZero => "division by zero",
+            OIntError::NotDivisible => "not exactly divisible",
+            OIntError::NoInverse => "element has no inverse",
+            OIntError::NotAUnit => "not a unit",
+            OIntError::InvalidHalfInteger => "components must be all integers or all half-integers",
+            OIntError::InvalidFormat => "invalid format",
+            OIntError::NotInSubalgebra => "not in the quaternion subalgebra",
+            OIntError::NotInLattice => "not a point of the E8 lattice",
+        })
+    }
+}
+
+// Prefixed with the ring the error came from, e.g. "OInt: division by zero"
+impl fmt::Display for EntropyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EntropyError::CInt(e) => write!(f, "CInt: {}", e),
+            EntropyError::EInt(e) => write!(f, "EInt: {}", e),
+            EntropyError::HInt(e) => write!(f, "HInt: {}", e),
+            EntropyError::OInt(e) => write!(f, "OInt: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CIntError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EIntError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HIntError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OIntError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EntropyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EntropyError::CInt(e) => Some(e),
+            EntropyError::EInt(e) => Some(e),
+            EntropyError::HInt(e) => Some(e),
+            EntropyError::OInt(e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::types::EInt;
+
+// The hexagonal lattice A₂ as the Eisenstein integers: (a, b) are the
+// coordinates of a + bω in the basis 1, ω (120° apart), and squared lengths
+// are the Eisenstein norm a² - ab + b².
+impl EInt {
+    pub fn to_lattice_vector(self) -> (i32, i32) {
+        (self.a, self.b)
+    }
+
+    pub fn from_lattice_vector(v: (i32, i32)) -> Self {
+        EInt::new(v.0, v.1)
+    }
+
+    pub fn lattice_distance_squared(self, other: Self) -> i32 {
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        da * da - da * db + db * db
+    }
+
+    pub fn lattice_norm_squared(self) -> i32 {
+        self.a * self.a - self.a * self.b + self.b * self.b
+    }
+
+    // lattice_norm_squared without i32 overflow; the same as norm_squared()
+    pub fn lattice_norm_squared_u64(self) -> u64 {
+        self.norm_squared()
+    }
+
+    pub fn closest_lattice_point_int(target: (i32, i32)) -> Self {
+        EInt::new(target.0, target.1)
+    }
+
+    // The 60° basis 1 and 1 + ω = -ω², both of norm 1
+    pub fn fundamental_domain() -> ((i32, i32), (i32, i32)) {
+        ((1, 0), (1, 1))
+    }
+
+    // Cell area √3/2, as (num, den) of the coefficient of √3
+    pub fn lattice_volume() -> (i32, i32) {
+        (1, 2)
+    }
+
+    // Coordinates in the 1, ω basis: every integer pair is a lattice point
+    pub fn is_in_lattice(_v: (i32, i32)) -> bool {
+        true
+    }
+
+    // is_in_lattice on the point's own coordinates: always true
+    pub fn is_lattice_member(self) -> bool {
+        EInt::is_in_lattice(self.to_lattice_vector())
+    }
+}
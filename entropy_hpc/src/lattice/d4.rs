@@ -1,11 +1,14 @@
-use crate::types::HInt;
+use crate::types::{reduce_ratio, HInt};
+use crate::lattice::{dot_doubled, D4Vector};
+#[cfg(feature = "std")]
+use crate::lattice::{closest_dn_plus_doubled, distance_squared_doubled};
 
 impl HInt {
-    pub fn to_lattice_vector(self) -> (i32, i32, i32, i32) {
+    pub fn to_lattice_vector(self) -> D4Vector {
         (self.a, self.b, self.c, self.d)
     }
 
-    pub fn from_lattice_vector(v: (i32, i32, i32, i32)) -> Self {
+    pub fn from_lattice_vector(v: D4Vector) -> Self {
         HInt::new(v.0, v.1, v.2, v.3)
     }
 
@@ -17,15 +20,66 @@ impl HInt {
         (da * da + db * db + dc * dc + dd * dd) / 4
     }
 
+    pub fn checked_lattice_distance_squared(self, other: Self) -> Option<i64> {
+        let diffs = [
+            self.a as i64 - other.a as i64,
+            self.b as i64 - other.b as i64,
+            self.c as i64 - other.c as i64,
+            self.d as i64 - other.d as i64,
+        ];
+        let mut sum: i64 = 0;
+        for x in diffs {
+            sum = sum.checked_add(x.checked_mul(x)?)?;
+        }
+        Some(sum / 4)
+    }
+
+    // Lossy floating companion to lattice_distance_squared. The raw
+    // differences are squared in f64 and halved after the sqrt, so there is
+    // neither i32 overflow nor the truncating /4.
+    #[cfg(feature = "std")]
+    pub fn lattice_distance(self, other: Self) -> f64 {
+        let (x, y) = (self.raw_components(), other.raw_components());
+        let sum: f64 = x.iter().zip(&y)
+            .map(|(&p, &q)| {
+                let d = (p as i64 - q as i64) as f64;
+                d * d
+            })
+            .sum();
+        sum.sqrt() / 2.0
+    }
+
     pub fn lattice_norm_squared(self) -> i32 {
         (self.a * self.a + self.b * self.b + self.c * self.c + self.d * self.d) / 4
     }
 
-    pub fn closest_lattice_point_int(target: (i32, i32, i32, i32)) -> Self {
+    // Σ stored² / 4 as a reduced (numerator, denominator) pair, summed in u64.
+    // Same-parity values always give denominator 1 (four odd squares sum to
+    // 4 mod 8); only mixed-parity values from from_raw leave a remainder,
+    // which lattice_norm_squared would truncate.
+    pub fn lattice_norm_squared_exact(self) -> (u64, u64) {
+        let sum: u64 = self.raw_components().iter().map(|&x| (x as i64 * x as i64) as u64).sum();
+        reduce_ratio(sum as u128, 4)
+    }
+
+    // lattice_norm_squared summed in i64 so large coordinates cannot wrap
+    // negative; the same value as the algebraic norm_squared()
+    pub fn lattice_norm_squared_u64(self) -> u64 {
+        self.norm_squared()
+    }
+
+    // Inner product of the real coordinate vectors as a reduced (numerator,
+    // denominator) pair. Hurwitz integers are not integral for it, e.g.
+    // ½(1,1,1,1) · ½(1,1,1,-1) = ½; bilinear_form is twice this, an integer.
+    pub fn lattice_dot(self, other: Self) -> (i64, u64) {
+        dot_doubled(&self.raw_components(), &other.raw_components())
+    }
+
+    pub fn closest_lattice_point_int(target: D4Vector) -> Self {
         HInt::new(target.0, target.1, target.2, target.3)
     }
 
-    pub fn fundamental_domain() -> ((i32, i32, i32, i32), (i32, i32, i32, i32)) {
+    pub fn fundamental_domain() -> (D4Vector, D4Vector) {
         ((2, 0, 0, 0), (0, 2, 2, 2))
     }
 
@@ -33,10 +87,34 @@ impl HInt {
         1
     }
 
-    pub fn is_in_lattice(v: (i32, i32, i32, i32)) -> bool {
+    pub fn is_in_lattice(v: D4Vector) -> bool {
         let sum = v.0 + v.1 + v.2 + v.3;
         let all_even = v.0 % 2 == 0 && v.1 % 2 == 0 && v.2 % 2 == 0 && v.3 % 2 == 0;
         let all_odd = v.0 % 2 != 0 && v.1 % 2 != 0 && v.2 % 2 != 0 && v.3 % 2 != 0;
         (all_even || all_odd) && sum % 4 == 0
     }
+
+    // is_in_lattice on the point's own stored (*2) coordinates: the even-sum
+    // D₄ ∪ (D₄ + ½) model, not the Hurwitz ring. Only Hurwitz integers whose
+    // actual coordinates have an even sum are members, so one(), i() and
+    // ½(1,1,1,-1) are not.
+    pub fn is_lattice_member(self) -> bool {
+        HInt::is_in_lattice(self.to_lattice_vector())
+    }
+
+    // Nearest point of the D₄ lattice accepted by is_in_lattice (integer or
+    // half-integer coordinates with even sum) to a real target
+    #[cfg(feature = "std")]
+    pub fn closest_lattice_point(target: [f64; 4]) -> Self {
+        let [a, b, c, d] = closest_dn_plus_doubled(target);
+        HInt::from_raw(a, b, c, d)
+    }
+
+    // Squared distance from target to its nearest lattice point; at most the
+    // squared covering radius, 1
+    #[cfg(feature = "std")]
+    pub fn quantization_error_squared(target: [f64; 4]) -> f64 {
+        let p = HInt::closest_lattice_point(target);
+        distance_squared_doubled(&target, &p.raw_components())
+    }
 }
@@ -1,11 +1,22 @@
-use crate::types::OInt;
+use crate::types::{reduce_ratio, OInt};
+use crate::lattice::{dot_doubled, E8Vector};
+#[cfg(feature = "std")]
+use crate::lattice::{closest_dn_plus_doubled, distance_squared_doubled};
+
+// The two cosets that make up E₈ = D₈ ∪ (D₈ + ½): all-integer and
+// all-half-integer points, each with even coordinate sum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum E8Coset {
+    Integer,
+    HalfInteger,
+}
 
 impl OInt {
-    pub fn to_lattice_vector(self) -> (i32, i32, i32, i32, i32, i32, i32, i32) {
+    pub fn to_lattice_vector(self) -> E8Vector {
         (self.a, self.b, self.c, self.d, self.e, self.f, self.g, self.h)
     }
 
-    pub fn from_lattice_vector(v: (i32, i32, i32, i32, i32, i32, i32, i32)) -> Self {
+    pub fn from_lattice_vector(v: E8Vector) -> Self {
         OInt::new(v.0, v.1, v.2, v.3, v.4, v.5, v.6, v.7)
     }
 
@@ -21,16 +32,67 @@ impl OInt {
         (da*da + db*db + dc*dc + dd*dd + de*de + df*df + dg*dg + dh*dh) / 4
     }
 
+    pub fn checked_lattice_distance_squared(self, other: Self) -> Option<i64> {
+        let diffs = [
+            self.a as i64 - other.a as i64,
+            self.b as i64 - other.b as i64,
+            self.c as i64 - other.c as i64,
+            self.d as i64 - other.d as i64,
+            self.e as i64 - other.e as i64,
+            self.f as i64 - other.f as i64,
+            self.g as i64 - other.g as i64,
+            self.h as i64 - other.h as i64,
+        ];
+        let mut sum: i64 = 0;
+        for x in diffs {
+            sum = sum.checked_add(x.checked_mul(x)?)?;
+        }
+        Some(sum / 4)
+    }
+
+    // Lossy floating companion to lattice_distance_squared. The raw
+    // differences are squared in f64 and halved after the sqrt, so there is
+    // neither i32 overflow nor the truncating /4.
+    #[cfg(feature = "std")]
+    pub fn lattice_distance(self, other: Self) -> f64 {
+        let (x, y) = (self.raw_components(), other.raw_components());
+        let sum: f64 = x.iter().zip(&y)
+            .map(|(&p, &q)| {
+                let d = (p as i64 - q as i64) as f64;
+                d * d
+            })
+            .sum();
+        sum.sqrt() / 2.0
+    }
+
     pub fn lattice_norm_squared(self) -> i32 {
         (self.a*self.a + self.b*self.b + self.c*self.c + self.d*self.d +
          self.e*self.e + self.f*self.f + self.g*self.g + self.h*self.h) / 4
     }
 
-    pub fn closest_lattice_point_int(target: (i32, i32, i32, i32, i32, i32, i32, i32)) -> Self {
+    // Exact Σ stored² / 4 as for HInt; denominator 1 unless the parities are mixed
+    pub fn lattice_norm_squared_exact(self) -> (u64, u64) {
+        let sum: u64 = self.raw_components().iter().map(|&x| (x as i64 * x as i64) as u64).sum();
+        reduce_ratio(sum as u128, 4)
+    }
+
+    // As for HInt, the i64-accumulated norm_squared()
+    pub fn lattice_norm_squared_u64(self) -> u64 {
+        self.norm_squared()
+    }
+
+    // Inner product of the real coordinate vectors as a reduced (numerator,
+    // denominator) pair; half-integral for octonion units such as
+    // ½(1,1,1,1,0,0,0,0) and ½(1,1,0,0,1,1,0,0). bilinear_form is twice this.
+    pub fn lattice_dot(self, other: Self) -> (i64, u64) {
+        dot_doubled(&self.raw_components(), &other.raw_components())
+    }
+
+    pub fn closest_lattice_point_int(target: E8Vector) -> Self {
         OInt::new(target.0, target.1, target.2, target.3, target.4, target.5, target.6, target.7)
     }
 
-    pub fn fundamental_domain() -> ((i32, i32, i32, i32, i32, i32, i32, i32), (i32, i32, i32, i32, i32, i32, i32, i32)) {
+    pub fn fundamental_domain() -> (E8Vector, E8Vector) {
         ((2, 0, 0, 0, 0, 0, 0, 0), (0, 2, 2, 2, 2, 0, 0, 0))
     }
 
@@ -38,7 +100,7 @@ impl OInt {
         1
     }
 
-    pub fn is_in_lattice(v: (i32, i32, i32, i32, i32, i32, i32, i32)) -> bool {
+    pub fn is_in_lattice(v: E8Vector) -> bool {
         let sum = v.0 + v.1 + v.2 + v.3 + v.4 + v.5 + v.6 + v.7;
         let all_even = v.0 % 2 == 0 && v.1 % 2 == 0 && v.2 % 2 == 0 && v.3 % 2 == 0 &&
                        v.4 % 2 == 0 && v.5 % 2 == 0 && v.6 % 2 == 0 && v.7 % 2 == 0;
@@ -46,4 +108,89 @@ impl OInt {
                       v.4 % 2 != 0 && v.5 % 2 != 0 && v.6 % 2 != 0 && v.7 % 2 != 0;
         (all_even || all_odd) && sum % 4 == 0
     }
+
+    // One of the two parity classes, with the even-sum rule of is_in_lattice
+    // on top; from_raw and from_le_bytes can produce values that fail this.
+    // The ring of integral octonions is is_integral_octonion instead.
+    pub fn is_valid_e8_element(self) -> bool {
+        (self.is_integer() || self.is_half_integer()) && OInt::is_in_lattice(self.to_lattice_vector())
+    }
+
+    // is_in_lattice on the point's own stored (*2) coordinates: the even-sum
+    // D₈ ∪ (D₈ + ½) model, the same answer as is_valid_e8_element and not the
+    // ring of is_integral_octonion. one(), every e_i and all the mixed-parity
+    // units are not members.
+    pub fn is_lattice_member(self) -> bool {
+        OInt::is_in_lattice(self.to_lattice_vector())
+    }
+
+    // Which coset of D₈ the point lies in, or None if it is not in E₈
+    pub fn e8_coset(self) -> Option<E8Coset> {
+        if !OInt::is_in_lattice(self.to_lattice_vector()) {
+            None
+        } else if self.a % 2 == 0 {
+            Some(E8Coset::Integer)
+        } else {
+            Some(E8Coset::HalfInteger)
+        }
+    }
+
+    // All lattice points with lattice_norm_squared <= radius_squared, in a
+    // fixed order: by norm, then lexicographically by the stored (*2)
+    // components, so exact lists can be asserted. In this scaling the roots
+    // have norm 2, so radius 2 gives the origin plus the 240 minimal vectors,
+    // starting (-2, -2, 0, ...), (-2, 0, -2, 0, ...).
+    #[cfg(feature = "std")]
+    pub fn vectors_within(radius_squared: i32) -> Vec<Self> {
+        let mut found = Vec::new();
+        if radius_squared >= 0 {
+            // Σ stored² <= 4 * radius_squared in doubled coordinates
+            let mut coords = [0i32; 8];
+            collect_within(&mut coords, 0, 4 * radius_squared as i64, &mut found);
+        }
+        found.sort_by_key(|p| (p.lattice_norm_squared(), p.raw_components()));
+        found
+    }
+
+    // Nearest E₈ point to a target in real coordinates (the half of the
+    // stored values), per the even-sum convention of is_in_lattice
+    #[cfg(feature = "std")]
+    pub fn closest_lattice_point(target: [f64; 8]) -> Self {
+        let [a, b, c, d, e, f, g, h] = closest_dn_plus_doubled(target);
+        OInt::from_raw(a, b, c, d, e, f, g, h)
+    }
+
+    // Squared distance from target to its nearest E₈ point; at most the
+    // squared covering radius, 1
+    #[cfg(feature = "std")]
+    pub fn quantization_error_squared(target: [f64; 8]) -> f64 {
+        let p = OInt::closest_lattice_point(target);
+        distance_squared_doubled(&target, &p.raw_components())
+    }
+}
+
+// Fix coordinates one at a time, each bounded by the remaining squared-norm
+// budget; all coordinates share the parity of the first, so the rest step by 2
+#[cfg(feature = "std")]
+fn collect_within(coords: &mut [i32; 8], k: usize, budget: i64, out: &mut Vec<OInt>) {
+    if k == 8 {
+        let [a, b, c, d, e, f, g, h] = *coords;
+        if OInt::is_in_lattice((a, b, c, d, e, f, g, h)) {
+            out.push(OInt::from_raw(a, b, c, d, e, f, g, h));
+        }
+        return;
+    }
+
+    let mut bound = 0i64;
+    while (bound + 1) * (bound + 1) <= budget {
+        bound += 1;
+    }
+
+    for x in -bound..=bound {
+        if k > 0 && (x - coords[0] as i64) % 2 != 0 {
+            continue;
+        }
+        coords[k] = x as i32;
+        collect_within(coords, k + 1, budget - x * x, out);
+    }
 }
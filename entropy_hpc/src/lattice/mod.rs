@@ -1,3 +1,171 @@
 pub mod z2;
+pub mod a2;
 pub mod d4;
 pub mod e8;
+
+pub use e8::E8Coset;
+
+#[cfg(feature = "std")]
+use crate::types::{CInt, HInt, OInt};
+use crate::types::reduce_ratio;
+
+// Coordinate tuples used by the D₄ and E₈ lattice helpers
+pub type D4Vector = (i32, i32, i32, i32);
+pub type E8Vector = (i32, i32, i32, i32, i32, i32, i32, i32);
+
+// Nearest point of Dₙ (integer vectors with even coordinate sum): round every
+// coordinate, and if the sum comes out odd re-round the worst one the other way
+#[cfg(feature = "std")]
+fn closest_dn<const N: usize>(x: [f64; N]) -> [f64; N] {
+    let mut r = x.map(f64::round);
+    let sum: i64 = r.iter().map(|&v| v as i64).sum();
+
+    if sum % 2 != 0 {
+        let mut worst = 0;
+        for k in 1..N {
+            if (x[k] - r[k]).abs() > (x[worst] - r[worst]).abs() {
+                worst = k;
+            }
+        }
+        r[worst] += if x[worst] > r[worst] { 1.0 } else { -1.0 };
+    }
+
+    r
+}
+
+// Nearest point of Dₙ ∪ (Dₙ + ½), the set `is_in_lattice` accepts for D₄ and
+// E₈, returned in the doubled (*2) storage convention. Decodes both cosets
+// and keeps the closer (Conway & Sloane, SPLAG ch. 20).
+#[cfg(feature = "std")]
+pub(crate) fn closest_dn_plus_doubled<const N: usize>(x: [f64; N]) -> [i32; N] {
+    let int_point = closest_dn(x);
+    let half_point = closest_dn(x.map(|v| v - 0.5)).map(|v| v + 0.5);
+
+    let dist = |p: &[f64; N]| x.iter().zip(p).map(|(a, b)| (a - b) * (a - b)).sum::<f64>();
+    let best = if dist(&half_point) < dist(&int_point) { half_point } else { int_point };
+
+    best.map(|v| (v * 2.0).round() as i32)
+}
+
+// Squared Euclidean distance from a float target to doubled (*2) coordinates
+#[cfg(feature = "std")]
+pub(crate) fn distance_squared_doubled(target: &[f64], doubled: &[i32]) -> f64 {
+    target.iter().zip(doubled).map(|(&t, &v)| {
+        let d = t - v as f64 / 2.0;
+        d * d
+    }).sum()
+}
+
+// Inner product of two doubled (*2) coordinate vectors, Σ stored products / 4
+// summed in i64, as a reduced (numerator, denominator) pair
+pub(crate) fn dot_doubled(x: &[i32], y: &[i32]) -> (i64, u64) {
+    let sum: i64 = x.iter().zip(y).map(|(&p, &q)| p as i64 * q as i64).sum();
+    let (num, den) = reduce_ratio(sum.unsigned_abs() as u128, 4);
+    (sum.signum() * num as i64, den)
+}
+
+// Common interface of the Z², D₄ and E₈ lattices. Vectors are in the stored
+// coordinates returned by to_lattice_vector (doubled for D₄ and E₈), which is
+// also what is_member and closest take.
+#[cfg(feature = "std")]
+pub trait Lattice: Copy {
+    type Point: Copy;
+
+    fn to_vector(self) -> Self::Point;
+    // Inverse of to_vector
+    fn from_vector(v: Self::Point) -> Self;
+    fn norm_squared(self) -> i32;
+    fn distance_squared(self, other: Self) -> i32;
+    fn is_member(v: Self::Point) -> bool;
+    // Nearest lattice point to v
+    fn closest(v: Self::Point) -> Self;
+}
+
+#[cfg(feature = "std")]
+impl Lattice for CInt {
+    type Point = (i32, i32);
+
+    fn to_vector(self) -> Self::Point {
+        self.to_lattice_vector()
+    }
+
+    fn from_vector(v: Self::Point) -> Self {
+        CInt::from_lattice_vector(v)
+    }
+
+    fn norm_squared(self) -> i32 {
+        self.lattice_norm_squared()
+    }
+
+    fn distance_squared(self, other: Self) -> i32 {
+        self.lattice_distance_squared(other)
+    }
+
+    fn is_member(v: Self::Point) -> bool {
+        CInt::is_in_lattice(v)
+    }
+
+    fn closest(v: Self::Point) -> Self {
+        CInt::from_lattice_vector(v)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Lattice for HInt {
+    type Point = D4Vector;
+
+    fn to_vector(self) -> Self::Point {
+        self.to_lattice_vector()
+    }
+
+    fn from_vector(v: Self::Point) -> Self {
+        HInt::from_raw(v.0, v.1, v.2, v.3)
+    }
+
+    fn norm_squared(self) -> i32 {
+        self.lattice_norm_squared()
+    }
+
+    fn distance_squared(self, other: Self) -> i32 {
+        self.lattice_distance_squared(other)
+    }
+
+    fn is_member(v: Self::Point) -> bool {
+        HInt::is_in_lattice(v)
+    }
+
+    fn closest(v: Self::Point) -> Self {
+        let halves = [v.0, v.1, v.2, v.3].map(|x| x as f64 / 2.0);
+        HInt::closest_lattice_point(halves)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Lattice for OInt {
+    type Point = E8Vector;
+
+    fn to_vector(self) -> Self::Point {
+        self.to_lattice_vector()
+    }
+
+    fn from_vector(v: Self::Point) -> Self {
+        OInt::from_raw(v.0, v.1, v.2, v.3, v.4, v.5, v.6, v.7)
+    }
+
+    fn norm_squared(self) -> i32 {
+        self.lattice_norm_squared()
+    }
+
+    fn distance_squared(self, other: Self) -> i32 {
+        self.lattice_distance_squared(other)
+    }
+
+    fn is_member(v: Self::Point) -> bool {
+        OInt::is_in_lattice(v)
+    }
+
+    fn closest(v: Self::Point) -> Self {
+        let halves = [v.0, v.1, v.2, v.3, v.4, v.5, v.6, v.7].map(|x| x as f64 / 2.0);
+        OInt::closest_lattice_point(halves)
+    }
+}
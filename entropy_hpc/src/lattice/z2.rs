@@ -23,6 +23,16 @@ impl CInt {
         self.a * self.a + self.b * self.b
     }
 
+    /// 4a. Euclidean inner product of the coordinate vectors
+    pub fn lattice_dot(self, other: Self) -> i32 {
+        self.a * other.a + self.b * other.b
+    }
+
+    /// 4b. Norm squared accumulated in i64 so it cannot overflow (= norm_squared)
+    pub fn lattice_norm_squared_u64(self) -> u64 {
+        self.norm_squared()
+    }
+
     /// 5. Find closest lattice point (compare squared distances)
     pub fn closest_lattice_point_int(target: (i32, i32)) -> Self {
         CInt::new(target.0, target.1)
@@ -42,5 +52,44 @@ impl CInt {
     pub fn is_in_lattice(_v: (i32, i32)) -> bool {
         true
     }
+
+    /// 8b. is_in_lattice on the point's own coordinates: always true
+    pub fn is_lattice_member(self) -> bool {
+        CInt::is_in_lattice(self.to_lattice_vector())
+    }
+
+    /// 9. Squared distance accumulated in i64, None if it overflows
+    pub fn checked_lattice_distance_squared(self, other: Self) -> Option<i64> {
+        let da = self.a as i64 - other.a as i64;
+        let db = self.b as i64 - other.b as i64;
+        da.checked_mul(da)?.checked_add(db.checked_mul(db)?)
+    }
+
+    /// 9a. Euclidean distance, the lossy floating companion to 3
+    #[cfg(feature = "std")]
+    pub fn lattice_distance(self, other: Self) -> f64 {
+        let da = self.a as i64 - other.a as i64;
+        let db = self.b as i64 - other.b as i64;
+        (da as f64).hypot(db as f64)
+    }
+
+    /// 10. Nearest lattice point to a real target (coordinate-wise rounding)
+    #[cfg(feature = "std")]
+    pub fn closest_lattice_point(target: (f64, f64)) -> Self {
+        CInt::new(target.0.round() as i32, target.1.round() as i32)
+    }
+
+    /// 11. Squared quantization error, at most the squared covering radius 1/2
+    #[cfg(feature = "std")]
+    pub fn quantization_error_squared(target: (f64, f64)) -> f64 {
+        let p = CInt::closest_lattice_point(target);
+        let (dx, dy) = (target.0 - p.a as f64, target.1 - p.b as f64);
+        dx * dx + dy * dy
+    }
 }
 
+impl From<CInt> for (i32, i32) {
+    fn from(z: CInt) -> Self {
+        z.to_lattice_vector()
+    }
+}
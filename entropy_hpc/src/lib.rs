@@ -1,6 +1,16 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
 pub mod types;
+pub mod error;
+#[cfg(feature = "std")]
 pub mod simd;
 pub mod lattice;
+#[cfg(feature = "std")]
+pub mod linalg;
+#[cfg(feature = "std")]
+pub mod poly;
 
-pub use types::{CInt, HInt, OInt};
+pub use types::{CInt, EInt, HInt, OInt};
+pub use error::EntropyError;
+#[cfg(feature = "std")]
 pub use simd::simd_engine;
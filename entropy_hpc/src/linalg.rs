@@ -0,0 +1,240 @@
+// src/linalg.rs
+//
+// Small dense matrices over the integer rings
+
+use crate::types::cint::{CIFraction, CIntError};
+use crate::types::{CInt, EInt, HInt, OInt};
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+// The ring operations a matrix entry needs
+pub trait RingElement:
+    Copy + PartialEq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+}
+
+macro_rules! impl_ring_element {
+    ($($t:ty),*) => {
+        $(
+            impl RingElement for $t {
+                fn zero() -> Self {
+                    <$t>::zero()
+                }
+
+                fn one() -> Self {
+                    <$t>::one()
+                }
+            }
+        )*
+    };
+}
+
+impl_ring_element!(CInt, EInt, HInt, OInt);
+
+// Row-major rows × cols matrix
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Mat<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: RingElement> Mat<T> {
+    // Panics if the rows have different lengths
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let n_rows = rows.len();
+        let n_cols = rows.first().map_or(0, |r| r.len());
+        assert!(rows.iter().all(|r| r.len() == n_cols), "matrix rows must have equal length");
+
+        Mat {
+            rows: n_rows,
+            cols: n_cols,
+            data: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        Mat { rows, cols, data: vec![T::zero(); rows * cols] }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut m = Mat::zero(n, n);
+        for i in 0..n {
+            m[(i, i)] = T::one();
+        }
+        m
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn to_rows(&self) -> Vec<Vec<T>> {
+        self.data.chunks(self.cols.max(1)).map(|r| r.to_vec()).collect()
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut t = Mat::zero(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                t[(j, i)] = self[(i, j)];
+            }
+        }
+        t
+    }
+
+    // Multiply every entry by s, with s on the left
+    pub fn scale(&self, s: T) -> Self {
+        Mat {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(|&x| s * x).collect(),
+        }
+    }
+}
+
+// Determinants need a commutative ring, so these are Gaussian-integer only
+impl Mat<CInt> {
+    // Cofactor expansion along the first row; meant for the small (2×2, 3×3)
+    // matrices of lattice work, as the cost grows factorially
+    pub fn det(&self) -> CInt {
+        assert!(self.is_square(), "determinant requires a square matrix");
+        match self.rows {
+            0 => CInt::one(),
+            1 => self[(0, 0)],
+            2 => self[(0, 0)] * self[(1, 1)] - self[(0, 1)] * self[(1, 0)],
+            n => (0..n).fold(CInt::zero(), |acc, j| {
+                let term = self[(0, j)] * self.minor(0, j).det();
+                if j % 2 == 0 { acc + term } else { acc - term }
+            }),
+        }
+    }
+
+    // Transpose of the cofactor matrix, so that M * adj(M) = det(M) * I
+    pub fn adjugate(&self) -> Self {
+        assert!(self.is_square(), "adjugate requires a square matrix");
+        let n = self.rows;
+        if n == 1 {
+            return Mat::identity(1);
+        }
+
+        let mut adj = Mat::zero(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                let cofactor = self.minor(i, j).det();
+                adj[(j, i)] = if (i + j) % 2 == 0 { cofactor } else { -cofactor };
+            }
+        }
+        adj
+    }
+
+    // adj(M) / det(M) as reduced fractions
+    pub fn inverse_fraction(&self) -> Result<Mat<CIFraction>, CIntError> {
+        let det = self.det();
+        if det.is_zero() {
+            return Err(CIntError::NoInverse);
+        }
+
+        let adj = self.adjugate();
+        let data = adj.data.iter()
+            .map(|&x| x.div_to_fraction(det).map(CIFraction::normalized))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Mat { rows: adj.rows, cols: adj.cols, data })
+    }
+
+    // Conjugate transpose M*, with (M*)[i][j] = conj(M[j][i])
+    pub fn adjoint(&self) -> Self {
+        let mut t = self.transpose();
+        t.data.iter_mut().for_each(CInt::conj_mut);
+        t
+    }
+
+    // M* = M; needs a square matrix with real diagonal entries
+    pub fn is_hermitian(&self) -> bool {
+        self.is_square() && self.adjoint() == *self
+    }
+
+    // The matrix with row i and column j removed
+    fn minor(&self, i: usize, j: usize) -> Self {
+        let data = (0..self.rows)
+            .filter(|&r| r != i)
+            .flat_map(|r| (0..self.cols).filter(move |&c| c != j).map(move |c| (r, c)))
+            .map(|(r, c)| self[(r, c)])
+            .collect();
+
+        Mat { rows: self.rows - 1, cols: self.cols - 1, data }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Mat<T> {
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.rows && j < self.cols, "matrix index out of range");
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Mat<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.rows && j < self.cols, "matrix index out of range");
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl<T: RingElement> Add for &Mat<T> {
+    type Output = Mat<T>;
+    fn add(self, rhs: Self) -> Mat<T> {
+        assert!(self.rows == rhs.rows && self.cols == rhs.cols, "matrix dimensions do not match");
+        Mat {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().zip(&rhs.data).map(|(&x, &y)| x + y).collect(),
+        }
+    }
+}
+
+impl<T: RingElement> Add for Mat<T> {
+    type Output = Mat<T>;
+    fn add(self, rhs: Self) -> Mat<T> {
+        &self + &rhs
+    }
+}
+
+// (AB)[i][j] = A[i][0] * B[0][j] + A[i][1] * B[1][j] + ..., each product with
+// the A entry on the left and the sum accumulated left to right. That order
+// matters for HInt (non-commutative) and OInt (also non-associative), where
+// (AB)C and A(BC) can differ.
+impl<T: RingElement> Mul for &Mat<T> {
+    type Output = Mat<T>;
+    fn mul(self, rhs: Self) -> Mat<T> {
+        assert!(self.cols == rhs.rows, "matrix dimensions do not match");
+        let mut out = Mat::zero(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for j in 0..rhs.cols {
+                let mut acc = T::zero();
+                for k in 0..self.cols {
+                    acc = acc + self[(i, k)] * rhs[(k, j)];
+                }
+                out[(i, j)] = acc;
+            }
+        }
+        out
+    }
+}
+
+impl<T: RingElement> Mul for Mat<T> {
+    type Output = Mat<T>;
+    fn mul(self, rhs: Self) -> Mat<T> {
+        &self * &rhs
+    }
+}
@@ -0,0 +1,225 @@
+// src/poly.rs
+//
+// Univariate polynomials over the integer rings
+
+use crate::linalg::RingElement;
+use crate::types::cint::CIntError;
+use crate::types::CInt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+// Coefficients low to high (coeffs[k] multiplies x^k), with no trailing zeros,
+// so the zero polynomial has no coefficients at all
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Poly<T> {
+    coeffs: Vec<T>,
+}
+
+impl<T: RingElement> Poly<T> {
+    pub fn new(mut coeffs: Vec<T>) -> Self {
+        while coeffs.last() == Some(&T::zero()) {
+            coeffs.pop();
+        }
+        Poly { coeffs }
+    }
+
+    pub fn zero() -> Self {
+        Poly { coeffs: Vec::new() }
+    }
+
+    pub fn constant(c: T) -> Self {
+        Poly::new(vec![c])
+    }
+
+    // The monomial x
+    pub fn x() -> Self {
+        Poly::new(vec![T::zero(), T::one()])
+    }
+
+    pub fn coeffs(&self) -> &[T] {
+        &self.coeffs
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    // None for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn leading_coeff(&self) -> T {
+        self.coeffs.last().copied().unwrap_or_else(T::zero)
+    }
+
+    // Horner evaluation; for non-commutative T this computes Σ c_k x^k with
+    // each coefficient on the left
+    pub fn eval(&self, x: T) -> T {
+        self.coeffs.iter().rev().fold(T::zero(), |acc, &c| acc * x + c)
+    }
+
+    // Multiply every coefficient by s, with s on the left
+    pub fn scale(&self, s: T) -> Self {
+        Poly::new(self.coeffs.iter().map(|&c| s * c).collect())
+    }
+
+    // self * x^k
+    fn shift(&self, k: usize) -> Self {
+        if self.is_zero() {
+            return Poly::zero();
+        }
+        let mut coeffs = vec![T::zero(); k];
+        coeffs.extend_from_slice(&self.coeffs);
+        Poly { coeffs }
+    }
+}
+
+impl Poly<CInt> {
+    pub fn derivative(&self) -> Self {
+        Poly::new(
+            self.coeffs.iter().enumerate().skip(1)
+                .map(|(k, &c)| CInt::new(k as i32, 0) * c)
+                .collect(),
+        )
+    }
+
+    // Normalized gcd of the coefficients (zero for the zero polynomial)
+    pub fn content(&self) -> CInt {
+        self.coeffs.iter().fold(CInt::zero(), |g, &c| CInt::gcd(g, c))
+    }
+
+    // self / content, with the leading coefficient moved to its normalized associate
+    pub fn primitive_part(&self) -> Self {
+        if self.is_zero() {
+            return Poly::zero();
+        }
+
+        let content = self.content();
+        let divided = Poly::new(
+            self.coeffs.iter().map(|&c| c.div_exact(content).unwrap()).collect(),
+        );
+        let lc = divided.leading_coeff();
+        let unit = lc.normalize().div_exact(lc).unwrap();
+        divided.scale(unit)
+    }
+
+    // Pseudo-division: returns (q, r, s) with s * self = q * d + r and
+    // deg r < deg d, where s = lc(d)^k. Over the field of fractions Q(i) the
+    // quotient and remainder are q / s and r / s; staying in Z[i] avoids
+    // carrying denominators through every step.
+    pub fn div_rem(&self, d: &Self) -> Result<(Self, Self, CInt), CIntError> {
+        let d_deg = d.degree().ok_or(CIntError::DivisionByZero)?;
+        let lc_d = d.leading_coeff();
+
+        let mut q = Poly::zero();
+        let mut r = self.clone();
+        let mut s = CInt::one();
+
+        while let Some(r_deg) = r.degree().filter(|&deg| deg >= d_deg) {
+            let lc_r = r.leading_coeff();
+            let shift = r_deg - d_deg;
+
+            // Exact step when lc(d) divides lc(r), otherwise scale everything by lc(d)
+            match lc_r.div_exact(lc_d) {
+                Ok(c) => {
+                    let term = Poly::constant(c).shift(shift);
+                    r = &r - &(&term * d);
+                    q = &q + &term;
+                }
+                Err(_) => {
+                    let term = Poly::constant(lc_r).shift(shift);
+                    r = &r.scale(lc_d) - &(&term * d);
+                    q = &q.scale(lc_d) + &term;
+                    s = s * lc_d;
+                }
+            }
+        }
+
+        Ok((q, r, s))
+    }
+
+    // Greatest common divisor over Q(i), returned as a primitive polynomial
+    // with normalized leading coefficient (so gcd(f, g) is 1 for coprime f, g)
+    pub fn gcd(a: &Self, b: &Self) -> Self {
+        let mut x = a.primitive_part();
+        let mut y = b.primitive_part();
+
+        while !y.is_zero() {
+            let (_, r, _) = x.div_rem(&y).unwrap();
+            x = y;
+            y = r.primitive_part();
+        }
+
+        x
+    }
+}
+
+impl<T: RingElement> Add for &Poly<T> {
+    type Output = Poly<T>;
+    fn add(self, rhs: Self) -> Poly<T> {
+        let n = self.coeffs.len().max(rhs.coeffs.len());
+        let coeff = |p: &Poly<T>, k: usize| p.coeffs.get(k).copied().unwrap_or_else(T::zero);
+        Poly::new((0..n).map(|k| coeff(self, k) + coeff(rhs, k)).collect())
+    }
+}
+
+impl<T: RingElement> Sub for &Poly<T> {
+    type Output = Poly<T>;
+    fn sub(self, rhs: Self) -> Poly<T> {
+        self + &(-rhs)
+    }
+}
+
+impl<T: RingElement> Neg for &Poly<T> {
+    type Output = Poly<T>;
+    fn neg(self) -> Poly<T> {
+        Poly { coeffs: self.coeffs.iter().map(|&c| -c).collect() }
+    }
+}
+
+// Convolution: the x^k coefficient is Σ_{i+j=k} self[i] * rhs[j], with the
+// self coefficient on the left and the sum taken in increasing i
+impl<T: RingElement> Mul for &Poly<T> {
+    type Output = Poly<T>;
+    fn mul(self, rhs: Self) -> Poly<T> {
+        if self.is_zero() || rhs.is_zero() {
+            return Poly::zero();
+        }
+
+        let mut coeffs = vec![T::zero(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, &x) in self.coeffs.iter().enumerate() {
+            for (j, &y) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j] + x * y;
+            }
+        }
+        Poly::new(coeffs)
+    }
+}
+
+impl<T: RingElement> Add for Poly<T> {
+    type Output = Poly<T>;
+    fn add(self, rhs: Self) -> Poly<T> {
+        &self + &rhs
+    }
+}
+
+impl<T: RingElement> Sub for Poly<T> {
+    type Output = Poly<T>;
+    fn sub(self, rhs: Self) -> Poly<T> {
+        &self - &rhs
+    }
+}
+
+impl<T: RingElement> Neg for Poly<T> {
+    type Output = Poly<T>;
+    fn neg(self) -> Poly<T> {
+        -&self
+    }
+}
+
+impl<T: RingElement> Mul for Poly<T> {
+    type Output = Poly<T>;
+    fn mul(self, rhs: Self) -> Poly<T> {
+        &self * &rhs
+    }
+}
@@ -55,8 +55,43 @@ pub fn cint_sub_batch(a: &[CInt; 4], b: &[CInt; 4]) -> [CInt; 4] {
     [a[0] - b[0], a[1] - b[1], a[2] - b[2], a[3] - b[3]]
 }
 
-// Mul: Scalar (complex mul is complex for SIMD)
+// Mul: each 64-bit lane holds one CInt (real low, imag high); _mm256_mul_epi32
+// multiplies the signed low halves into full i64 products, so overflow is exact
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn cint_mul_batch_avx2(a: &[CInt; 4], b: &[CInt; 4]) -> [CInt; 4] {
+    let a_vec = _mm256_loadu_si256(a.as_ptr() as *const __m256i);
+    let b_vec = _mm256_loadu_si256(b.as_ptr() as *const __m256i);
+    let a_imag = _mm256_srli_epi64(a_vec, 32);
+    let b_imag = _mm256_srli_epi64(b_vec, 32);
+
+    let real = _mm256_sub_epi64(_mm256_mul_epi32(a_vec, b_vec), _mm256_mul_epi32(a_imag, b_imag));
+    let imag = _mm256_add_epi64(_mm256_mul_epi32(a_vec, b_imag), _mm256_mul_epi32(a_imag, b_vec));
+
+    // Same overflow behavior as scalar Mul: v fits in i32 iff (v + 2^31) >> 32 == 0
+    let bias = _mm256_set1_epi64x(1 << 31);
+    let high = _mm256_or_si256(
+        _mm256_srli_epi64(_mm256_add_epi64(real, bias), 32),
+        _mm256_srli_epi64(_mm256_add_epi64(imag, bias), 32),
+    );
+    if _mm256_testz_si256(high, high) == 0 {
+        panic!("CInt multiplication overflow");
+    }
+
+    let result = _mm256_blend_epi32(real, _mm256_slli_epi64(imag, 32), 0b1010_1010);
+
+    let mut out = [CInt::zero(); 4];
+    _mm256_storeu_si256(out.as_mut_ptr() as *mut __m256i, result);
+    out
+}
+
 pub fn cint_mul_batch(a: &[CInt; 4], b: &[CInt; 4]) -> [CInt; 4] {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { cint_mul_batch_avx2(a, b) };
+        }
+    }
     [a[0] * b[0], a[1] * b[1], a[2] * b[2], a[3] * b[3]]
 }
 
@@ -106,7 +141,18 @@ pub fn cint_mul_arrays(a: &[CInt], b: &[CInt], out: &mut [CInt]) {
     assert_eq!(a.len(), b.len());
     assert_eq!(a.len(), out.len());
     
-    for i in 0..a.len() {
+    let len = a.len();
+    let chunks = len / 4;
+    
+    for i in 0..chunks {
+        let idx = i * 4;
+        let a_chunk: &[CInt; 4] = a[idx..idx+4].try_into().unwrap();
+        let b_chunk: &[CInt; 4] = b[idx..idx+4].try_into().unwrap();
+        let result = cint_mul_batch(a_chunk, b_chunk);
+        out[idx..idx+4].copy_from_slice(&result);
+    }
+    
+    for i in (chunks * 4)..len {
         out[i] = a[i] * b[i];
     }
 }
@@ -305,3 +351,169 @@ pub fn oint_mul_arrays(a: &[OInt], b: &[OInt], out: &mut [OInt]) {
     }
 }
 
+
+// ========================================================================
+// Reductions over the raw i32 components
+// ========================================================================
+
+// _mm256_mul_epi32 squares the low i32 of each 64-bit lane into an exact i64;
+// shifting right by 32 brings the high i32 down for the second multiply
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn sum_of_squares_avx2(values: &[i32]) -> u64 {
+    let chunks = values.chunks_exact(8);
+    let tail = chunks.remainder();
+
+    let mut acc = _mm256_setzero_si256();
+    for chunk in chunks {
+        let v = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+        let high = _mm256_srli_epi64(v, 32);
+        acc = _mm256_add_epi64(acc, _mm256_mul_epi32(v, v));
+        acc = _mm256_add_epi64(acc, _mm256_mul_epi32(high, high));
+    }
+
+    let mut lanes = [0u64; 4];
+    _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, acc);
+    lanes.iter().sum::<u64>() + sum_of_squares_scalar(tail)
+}
+
+fn sum_of_squares_scalar(values: &[i32]) -> u64 {
+    values.iter().map(|&x| (x as i64 * x as i64) as u64).sum()
+}
+
+// Σ x² over all values, exact as long as the total fits in a u64
+pub(crate) fn sum_of_squares(values: &[i32]) -> u64 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { sum_of_squares_avx2(values) };
+        }
+    }
+    sum_of_squares_scalar(values)
+}
+
+// Conjugation over packed values of `stride` components each (2, 4 or 8, so
+// a 256-bit register holds whole values): _mm256_sign_epi32 keeps the lanes
+// whose mask is positive (the scalar parts) and negates the rest
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn negate_non_scalar_avx2(values: &mut [i32], stride: usize) {
+    let mut signs = [-1i32; 8];
+    for s in signs.iter_mut().step_by(stride) {
+        *s = 1;
+    }
+    let mask = _mm256_loadu_si256(signs.as_ptr() as *const __m256i);
+
+    let mut chunks = values.chunks_exact_mut(8);
+    for chunk in &mut chunks {
+        let v = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+        _mm256_storeu_si256(chunk.as_mut_ptr() as *mut __m256i, _mm256_sign_epi32(v, mask));
+    }
+    negate_non_scalar_scalar(chunks.into_remainder(), stride);
+}
+
+fn negate_non_scalar_scalar(values: &mut [i32], stride: usize) {
+    for (k, x) in values.iter_mut().enumerate() {
+        if !k.is_multiple_of(stride) {
+            *x = -*x;
+        }
+    }
+}
+
+pub(crate) fn negate_non_scalar(values: &mut [i32], stride: usize) {
+    debug_assert!(8usize.is_multiple_of(stride) && values.len().is_multiple_of(stride));
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            unsafe { negate_non_scalar_avx2(values, stride) };
+            return;
+        }
+    }
+    negate_non_scalar_scalar(values, stride)
+}
+
+// Squared E₈ distance from each packed value (8 stored components) to target:
+// one value per register, so there is no partial chunk. The differences are
+// widened to i64 lanes and squared exactly with _mm256_mul_epi32. A result
+// past i32 panics on both paths: any |Δ| ≥ 2^20 already gives Δ²/4 > i32::MAX,
+// so rejecting those lanes up front keeps the eight squares summable in i64.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn distance_squared_stride8_avx2(values: &[i32], target: &[i32; 8]) -> Vec<i32> {
+    let t = _mm256_loadu_si256(target.as_ptr() as *const __m256i);
+    let t_lo = _mm256_cvtepi32_epi64(_mm256_castsi256_si128(t));
+    let t_hi = _mm256_cvtepi32_epi64(_mm256_extracti128_si256(t, 1));
+    let bias = _mm256_set1_epi64x(1 << 20);
+
+    values.chunks_exact(8)
+        .map(|chunk| {
+            let v = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+            let d_lo = _mm256_sub_epi64(_mm256_cvtepi32_epi64(_mm256_castsi256_si128(v)), t_lo);
+            let d_hi = _mm256_sub_epi64(_mm256_cvtepi32_epi64(_mm256_extracti128_si256(v, 1)), t_hi);
+
+            // |d| < 2^20 iff (d + 2^20) >> 21 == 0 as unsigned
+            let out_of_range = _mm256_or_si256(
+                _mm256_srli_epi64(_mm256_add_epi64(d_lo, bias), 21),
+                _mm256_srli_epi64(_mm256_add_epi64(d_hi, bias), 21),
+            );
+            assert!(_mm256_testz_si256(out_of_range, out_of_range) == 1, "E8 distance overflow");
+
+            let sq = _mm256_add_epi64(_mm256_mul_epi32(d_lo, d_lo), _mm256_mul_epi32(d_hi, d_hi));
+            let mut lanes = [0i64; 4];
+            _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, sq);
+            i32::try_from(lanes.iter().sum::<i64>() / 4).expect("E8 distance overflow")
+        })
+        .collect()
+}
+
+fn distance_squared_stride8_scalar(values: &[i32], target: &[i32; 8]) -> Vec<i32> {
+    values.chunks_exact(8)
+        .map(|chunk| {
+            let sum: i128 = chunk.iter().zip(target)
+                .map(|(&x, &y)| {
+                    let d = x as i128 - y as i128;
+                    d * d
+                })
+                .sum();
+            i32::try_from(sum / 4).expect("E8 distance overflow")
+        })
+        .collect()
+}
+
+pub(crate) fn distance_squared_stride8(values: &[i32], target: &[i32; 8]) -> Vec<i32> {
+    debug_assert!(values.len().is_multiple_of(8));
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { distance_squared_stride8_avx2(values, target) };
+        }
+    }
+    distance_squared_stride8_scalar(values, target)
+}
+
+// Index of the first packed value (8 stored components) equal to target: one
+// compare per value, all eight lanes equal exactly when the movemask is full
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn find_stride8_avx2(values: &[i32], target: &[i32; 8]) -> Option<usize> {
+    let t = _mm256_loadu_si256(target.as_ptr() as *const __m256i);
+    values.chunks_exact(8).position(|chunk| {
+        let v = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+        _mm256_movemask_epi8(_mm256_cmpeq_epi32(v, t)) == -1
+    })
+}
+
+fn find_stride8_scalar(values: &[i32], target: &[i32; 8]) -> Option<usize> {
+    values.chunks_exact(8).position(|chunk| chunk == target)
+}
+
+pub(crate) fn find_stride8(values: &[i32], target: &[i32; 8]) -> Option<usize> {
+    debug_assert!(values.len().is_multiple_of(8));
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { find_stride8_avx2(values, target) };
+        }
+    }
+    find_stride8_scalar(values, target)
+}
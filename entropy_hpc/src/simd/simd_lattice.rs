@@ -1,13 +1,26 @@
-#[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::*;
-
 use crate::types::{CInt, HInt, OInt};
+use crate::lattice::{distance_squared_doubled, D4Vector, E8Coset, E8Vector, Lattice};
+use crate::simd::simd_engine::{distance_squared_stride8, find_stride8, negate_non_scalar, sum_of_squares};
 
 /// SIMD lattice operations (AVX2)
 pub struct LatticeSimd;
 
 impl LatticeSimd {
-    // ════════════════════ Z² (A₂) ════════════════════
+    // ════════════════════ Any lattice ════════════════════
+
+    pub fn norm_squared_batch<L: Lattice>(points: &[L]) -> Vec<i32> {
+        points.iter().map(|&p| p.norm_squared()).collect()
+    }
+
+    pub fn distance_squared_batch<L: Lattice>(points: &[L], target: L) -> Vec<i32> {
+        points.iter().map(|&p| p.distance_squared(target)).collect()
+    }
+
+    pub fn in_lattice_batch<L: Lattice>(points: &[L::Point]) -> Vec<bool> {
+        points.iter().map(|&p| L::is_member(p)).collect()
+    }
+
+    // ════════════════════ Z² ════════════════════
     
     pub fn z2_to_lattice_batch(points: &[CInt]) -> Vec<(i32, i32)> {
         points.iter().map(|p| p.to_lattice_vector()).collect()
@@ -19,21 +32,73 @@ impl LatticeSimd {
 
     #[cfg(target_arch = "x86_64")]
     pub fn z2_distance_squared_batch(points: &[CInt], target: CInt) -> Vec<i32> {
-        unsafe {
-            points.chunks(4).flat_map(|chunk| {
-                let mut dists = vec![];
-                for p in chunk {
-                    dists.push(p.lattice_distance_squared(target));
-                }
-                dists
-            }).collect()
-        }
+        points.chunks(4).flat_map(|chunk| {
+            let mut dists = vec![];
+            for p in chunk {
+                dists.push(p.lattice_distance_squared(target));
+            }
+            dists
+        }).collect()
     }
 
     pub fn z2_norm_squared_batch(points: &[CInt]) -> Vec<i32> {
         points.iter().map(|p| p.lattice_norm_squared()).collect()
     }
 
+    // Σ lattice_norm_squared over the slice, accumulated in u64 so it cannot
+    // overflow where the per-point i32 norms would
+    pub fn z2_total_norm_squared(points: &[CInt]) -> u64 {
+        // CInt is #[repr(C)] with two i32 fields
+        let raw = unsafe { core::slice::from_raw_parts(points.as_ptr() as *const i32, points.len() * 2) };
+        sum_of_squares(raw)
+    }
+
+    // conj() of every point, four per AVX2 register
+    pub fn z2_conj_batch(points: &[CInt]) -> Vec<CInt> {
+        let mut out = points.to_vec();
+        let raw = unsafe { core::slice::from_raw_parts_mut(out.as_mut_ptr() as *mut i32, out.len() * 2) };
+        negate_non_scalar(raw, 2);
+        out
+    }
+
+    // Σ a[i] * b[i] as Gaussian integers, summed in i128 with one range check
+    // at the end, so intermediate sums may leave i32 (and i64) as long as the
+    // total does not. Panics on unequal lengths or a total outside i32.
+    pub fn z2_dot_product(a: &[CInt], b: &[CInt]) -> CInt {
+        assert_eq!(a.len(), b.len());
+        let (re, im) = a.iter().zip(b).fold((0i128, 0i128), |(re, im), (x, y)| {
+            let (xa, xb, ya, yb) = (x.a as i128, x.b as i128, y.a as i128, y.b as i128);
+            (re + xa * ya - xb * yb, im + xa * yb + xb * ya)
+        });
+
+        match (i32::try_from(re), i32::try_from(im)) {
+            (Ok(re), Ok(im)) => CInt::new(re, im),
+            _ => panic!("CInt dot product overflow"),
+        }
+    }
+
+    // All pairwise lattice_dot values: G[i][j] = basis[i] · basis[j]
+    pub fn z2_gram_matrix(basis: &[CInt]) -> Vec<Vec<i32>> {
+        basis.iter()
+            .map(|&x| basis.iter().map(|&y| x.lattice_dot(y)).collect())
+            .collect()
+    }
+
+    // gcd of all the points, normalized; zero for an empty or all-zero slice
+    pub fn z2_content(points: &[CInt]) -> CInt {
+        CInt::gcd_many(points)
+    }
+
+    // Every point divided by the content, so the result has content 1. A
+    // zero content leaves the points unchanged.
+    pub fn z2_primitive_part(points: &[CInt]) -> Vec<CInt> {
+        let content = Self::z2_content(points);
+        if content.is_zero() {
+            return points.to_vec();
+        }
+        points.iter().map(|&p| p.div_exact(content).unwrap()).collect()
+    }
+
     pub fn z2_closest_point_batch(targets: &[(i32, i32)]) -> Vec<CInt> {
         targets.iter().map(|&t| CInt::closest_lattice_point_int(t)).collect()
     }
@@ -50,38 +115,66 @@ impl LatticeSimd {
         points.iter().map(|&p| CInt::is_in_lattice(p)).collect()
     }
 
+    pub fn z2_filter_in_lattice(points: &[(i32, i32)]) -> Vec<CInt> {
+        points.iter()
+            .filter(|&&p| CInt::is_in_lattice(p))
+            .map(|&p| CInt::from_lattice_vector(p))
+            .collect()
+    }
+
     // ════════════════════ D₄ ════════════════════
 
-    pub fn d4_to_lattice_batch(points: &[HInt]) -> Vec<(i32, i32, i32, i32)> {
+    pub fn d4_to_lattice_batch(points: &[HInt]) -> Vec<D4Vector> {
         points.iter().map(|p| p.to_lattice_vector()).collect()
     }
 
-    pub fn d4_from_lattice_batch(vecs: &[(i32, i32, i32, i32)]) -> Vec<HInt> {
+    pub fn d4_from_lattice_batch(vecs: &[D4Vector]) -> Vec<HInt> {
         vecs.iter().map(|&v| HInt::from_lattice_vector(v)).collect()
     }
 
     #[cfg(target_arch = "x86_64")]
     pub fn d4_distance_squared_batch(points: &[HInt], target: HInt) -> Vec<i32> {
-        unsafe {
-            points.chunks(2).flat_map(|chunk| {
-                let mut dists = vec![];
-                for p in chunk {
-                    dists.push(p.lattice_distance_squared(target));
-                }
-                dists
-            }).collect()
-        }
+        points.chunks(2).flat_map(|chunk| {
+            let mut dists = vec![];
+            for p in chunk {
+                dists.push(p.lattice_distance_squared(target));
+            }
+            dists
+        }).collect()
     }
 
     pub fn d4_norm_squared_batch(points: &[HInt]) -> Vec<i32> {
         points.iter().map(|p| p.lattice_norm_squared()).collect()
     }
 
-    pub fn d4_closest_point_batch(targets: &[(i32, i32, i32, i32)]) -> Vec<HInt> {
+    // The /4 for the *2 storage is applied once to the whole sum; every
+    // Hurwitz value has a multiple of 4 as its raw sum of squares, so this
+    // equals the sum of the per-point norms
+    pub fn d4_total_norm_squared(points: &[HInt]) -> u64 {
+        // HInt is #[repr(C)] with four i32 fields
+        let raw = unsafe { core::slice::from_raw_parts(points.as_ptr() as *const i32, points.len() * 4) };
+        sum_of_squares(raw) / 4
+    }
+
+    pub fn d4_conj_batch(points: &[HInt]) -> Vec<HInt> {
+        let mut out = points.to_vec();
+        let raw = unsafe { core::slice::from_raw_parts_mut(out.as_mut_ptr() as *mut i32, out.len() * 4) };
+        negate_non_scalar(raw, 4);
+        out
+    }
+
+    // Pairwise lattice_dot values as (numerator, denominator) pairs
+    pub fn d4_gram_matrix(basis: &[HInt]) -> Vec<Vec<(i64, u64)>> {
+        basis.iter()
+            .map(|&x| basis.iter().map(|&y| x.lattice_dot(y)).collect())
+            .collect()
+    }
+
+    pub fn d4_closest_point_batch(targets: &[D4Vector]) -> Vec<HInt> {
         targets.iter().map(|&t| HInt::closest_lattice_point_int(t)).collect()
     }
 
-    pub fn d4_fundamental_domain_batch(count: usize) -> Vec<((i32, i32, i32, i32), (i32, i32, i32, i32))> {
+    pub fn d4_fundamental_domain_batch(count: usize) -> Vec<(D4Vector, D4Vector)> {
         vec![HInt::fundamental_domain(); count]
     }
 
@@ -89,36 +182,99 @@ impl LatticeSimd {
         vec![HInt::lattice_volume(); count]
     }
 
-    pub fn d4_in_lattice_batch(points: &[(i32, i32, i32, i32)]) -> Vec<bool> {
+    pub fn d4_in_lattice_batch(points: &[D4Vector]) -> Vec<bool> {
         points.iter().map(|&p| HInt::is_in_lattice(p)).collect()
     }
 
+    // is_in_lattice reads the tuple as doubled coordinates, so the passing
+    // points are taken as stored (*2) values
+    pub fn d4_filter_in_lattice(points: &[D4Vector]) -> Vec<HInt> {
+        points.iter()
+            .filter(|&&p| HInt::is_in_lattice(p))
+            .map(|&(a, b, c, d)| HInt::from_raw(a, b, c, d))
+            .collect()
+    }
+
     // ════════════════════ E₈ ════════════════════
 
-    pub fn e8_to_lattice_batch(points: &[OInt]) -> Vec<(i32, i32, i32, i32, i32, i32, i32, i32)> {
+    pub fn e8_to_lattice_batch(points: &[OInt]) -> Vec<E8Vector> {
         points.iter().map(|p| p.to_lattice_vector()).collect()
     }
 
-    pub fn e8_from_lattice_batch(vecs: &[(i32, i32, i32, i32, i32, i32, i32, i32)]) -> Vec<OInt> {
+    pub fn e8_from_lattice_batch(vecs: &[E8Vector]) -> Vec<OInt> {
         vecs.iter().map(|&v| OInt::from_lattice_vector(v)).collect()
     }
 
+    // Exact squared distances; panics if one does not fit an i32 rather than
+    // wrapping, so e8_adjacency_edges never sees a false 2
     #[cfg(target_arch = "x86_64")]
     pub fn e8_distance_squared_batch(points: &[OInt], target: OInt) -> Vec<i32> {
-        unsafe {
-            points.iter().map(|p| p.lattice_distance_squared(target)).collect()
+        let raw = unsafe { core::slice::from_raw_parts(points.as_ptr() as *const i32, points.len() * 8) };
+        distance_squared_stride8(raw, &target.raw_components())
+    }
+
+    // Index of the first point equal to needle (same stored components), eight
+    // lanes compared at once per point
+    pub fn e8_find(points: &[OInt], needle: OInt) -> Option<usize> {
+        let raw = unsafe { core::slice::from_raw_parts(points.as_ptr() as *const i32, points.len() * 8) };
+        find_stride8(raw, &needle.raw_components())
+    }
+
+    // Index pairs (i, j), i < j, at the minimal E₈ distance: squared
+    // distance 2, the norm of a root. One distance batch per point.
+    #[cfg(target_arch = "x86_64")]
+    pub fn e8_adjacency_edges(points: &[OInt]) -> Vec<(usize, usize)> {
+        let mut edges = Vec::new();
+        for (i, &p) in points.iter().enumerate() {
+            let rest = &points[i + 1..];
+            for (k, d) in Self::e8_distance_squared_batch(rest, p).into_iter().enumerate() {
+                if d == 2 {
+                    edges.push((i, i + 1 + k));
+                }
+            }
         }
+        edges
     }
 
     pub fn e8_norm_squared_batch(points: &[OInt]) -> Vec<i32> {
         points.iter().map(|p| p.lattice_norm_squared()).collect()
     }
 
-    pub fn e8_closest_point_batch(targets: &[(i32, i32, i32, i32, i32, i32, i32, i32)]) -> Vec<OInt> {
+    // As for D₄, divided by 4 once at the end
+    pub fn e8_total_norm_squared(points: &[OInt]) -> u64 {
+        // OInt is #[repr(C)] with eight i32 fields
+        let raw = unsafe { core::slice::from_raw_parts(points.as_ptr() as *const i32, points.len() * 8) };
+        sum_of_squares(raw) / 4
+    }
+
+    pub fn e8_conj_batch(points: &[OInt]) -> Vec<OInt> {
+        let mut out = points.to_vec();
+        let raw = unsafe { core::slice::from_raw_parts_mut(out.as_mut_ptr() as *mut i32, out.len() * 8) };
+        negate_non_scalar(raw, 8);
+        out
+    }
+
+    // Pairwise lattice_dot values as (numerator, denominator) pairs
+    pub fn e8_gram_matrix(basis: &[OInt]) -> Vec<Vec<(i64, u64)>> {
+        basis.iter()
+            .map(|&x| basis.iter().map(|&y| x.lattice_dot(y)).collect())
+            .collect()
+    }
+
+    pub fn e8_closest_point_batch(targets: &[E8Vector]) -> Vec<OInt> {
         targets.iter().map(|&t| OInt::closest_lattice_point_int(t)).collect()
     }
 
-    pub fn e8_fundamental_domain_batch(count: usize) -> Vec<((i32, i32, i32, i32, i32, i32, i32, i32), (i32, i32, i32, i32, i32, i32, i32, i32))> {
+    // Nearest E₈ point to each real target with its squared error, i.e.
+    // closest_lattice_point and quantization_error_squared in one decode
+    pub fn e8_snap_batch(targets: &[[f64; 8]]) -> Vec<(OInt, f64)> {
+        targets.iter().map(|t| {
+            let p = OInt::closest_lattice_point(*t);
+            (p, distance_squared_doubled(t, &p.raw_components()))
+        }).collect()
+    }
+
+    pub fn e8_fundamental_domain_batch(count: usize) -> Vec<(E8Vector, E8Vector)> {
         vec![OInt::fundamental_domain(); count]
     }
 
@@ -126,8 +282,20 @@ impl LatticeSimd {
         vec![OInt::lattice_volume(); count]
     }
 
-    pub fn e8_in_lattice_batch(points: &[(i32, i32, i32, i32, i32, i32, i32, i32)]) -> Vec<bool> {
+    pub fn e8_in_lattice_batch(points: &[E8Vector]) -> Vec<bool> {
         points.iter().map(|&p| OInt::is_in_lattice(p)).collect()
     }
+
+    pub fn e8_coset_batch(points: &[OInt]) -> Vec<Option<E8Coset>> {
+        points.iter().map(|p| p.e8_coset()).collect()
+    }
+
+    // As for D₄, the passing tuples are doubled coordinates
+    pub fn e8_filter_in_lattice(points: &[E8Vector]) -> Vec<OInt> {
+        points.iter()
+            .filter(|&&p| OInt::is_in_lattice(p))
+            .map(|&(a, b, c, d, e, f, g, h)| OInt::from_raw(a, b, c, d, e, f, g, h))
+            .collect()
+    }
 }
 
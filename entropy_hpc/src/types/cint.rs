@@ -1,4 +1,5 @@
-use std::ops::{Add, Sub, Mul, Neg};
+use core::ops::{Add, Sub, Mul, Div, Neg, Index, IndexMut};
+use crate::types::{collect_array, read_le, reduce_ratio, write_le};
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -7,9 +8,26 @@ pub enum CIntError {
     DivisionByZero,
     NotDivisible,
     NoInverse,
+    NotAUnit,
+    InvalidFormat,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+// How a Gaussian integer factors, as returned by CInt::classify. Primes come
+// in three kinds according to the rational prime p they divide: ramified over
+// p = 2, inert (p itself, p ≡ 3 mod 4) and split (norm p, p ≡ 1 mod 4).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GaussianKind {
+    Zero,
+    Unit,
+    RamifiedPrime,
+    InertPrime,
+    SplitPrime,
+    Composite,
+}
+
+// `==` and `Hash` compare the reduced form from `normalized()`, so value-equal
+// fractions such as 2/4 and 1/2 are equal and hash equally
+#[derive(Clone, Copy, Eq)]
 pub struct CIFraction {
     pub num: CInt,
     pub den: u64,
@@ -31,25 +49,109 @@ mod num_utils {
         }
         a
     }
+
+    // Trial division, fine for the norms an i32-component CInt can reach
+    pub fn is_prime(n: u64) -> bool {
+        if n < 2 {
+            return false;
+        }
+        if n.is_multiple_of(2) {
+            return n == 2;
+        }
+        let mut d = 3;
+        while d * d <= n {
+            if n.is_multiple_of(d) {
+                return false;
+            }
+            d += 2;
+        }
+        true
+    }
+
+    // The two integers around n / m (m > 0), nearest first. At an exact
+    // x.5 both are equally near; the one farther from zero goes first, as
+    // f64::round would pick it.
+    pub fn nearest_pair(n: i64, m: i64) -> [i64; 2] {
+        let f = n.div_euclid(m);
+        let twice_rem = 2 * n.rem_euclid(m);
+        if twice_rem > m || (twice_rem == m && n > 0) {
+            [f + 1, f]
+        } else {
+            [f, f + 1]
+        }
+    }
+
+    // (g, u, v) with u*a + v*b = g = gcd(a, b) >= 0
+    pub fn integer_xgcd(a: i64, b: i64) -> (i64, i64, i64) {
+        let (mut old_r, mut r) = (a, b);
+        let (mut old_u, mut u) = (1i64, 0i64);
+        let (mut old_v, mut v) = (0i64, 1i64);
+        while r != 0 {
+            let q = old_r / r;
+            (old_r, r) = (r, old_r - q * r);
+            (old_u, u) = (u, old_u - q * u);
+            (old_v, v) = (v, old_v - q * v);
+        }
+        if old_r < 0 {
+            (-old_r, -old_u, -old_v)
+        } else {
+            (old_r, old_u, old_v)
+        }
+    }
 }
 
 impl CInt {
-    pub fn new(a: i32, b: i32) -> Self {
+    pub const fn new(a: i32, b: i32) -> Self {
         CInt { a, b }
     }
 
-    pub fn zero() -> Self {
+    pub fn components(self) -> [i32; 2] {
+        [self.a, self.b]
+    }
+
+    // (a, b) as little-endian i32s, matching the #[repr(C)] layout on LE targets
+    pub fn to_le_bytes(self) -> [u8; 8] {
+        let mut out = [0u8; 8];
+        write_le(&self.components(), &mut out);
+        out
+    }
+
+    pub fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        let mut c = [0i32; 2];
+        read_le(&bytes, &mut c);
+        CInt::new(c[0], c[1])
+    }
+
+    // Exactly two values (real, imaginary); InvalidFormat for any other count
+    pub fn from_components<I: IntoIterator<Item = i32>>(iter: I) -> Result<Self, CIntError> {
+        let [a, b] = collect_array(iter).ok_or(CIntError::InvalidFormat)?;
+        Ok(CInt::new(a, b))
+    }
+
+    pub const fn zero() -> Self {
         CInt::new(0, 0)
     }
 
-    pub fn one() -> Self {
+    pub const fn one() -> Self {
         CInt::new(1, 0)
     }
 
-    pub fn i() -> Self {
+    pub const fn i() -> Self {
         CInt::new(0, 1)
     }
 
+    pub const UNIT_COUNT: usize = 4;
+
+    // The units 1, i, -1, -i by index, None past UNIT_COUNT
+    pub fn unit(index: usize) -> Option<Self> {
+        [CInt::one(), CInt::i(), -CInt::one(), -CInt::i()].get(index).copied()
+    }
+
+    #[cfg(feature = "std")]
+    pub fn units() -> Vec<Self> {
+        (0..Self::UNIT_COUNT).filter_map(Self::unit).collect()
+    }
+
     pub fn is_zero(self) -> bool {
         self.a == 0 && self.b == 0
     }
@@ -62,12 +164,51 @@ impl CInt {
         CInt { a: self.a, b: -self.b }
     }
 
+    // conj() in place
+    pub fn conj_mut(&mut self) {
+        self.b = -self.b;
+    }
+
     pub fn norm_squared(self) -> u64 {
         let a2: i64 = self.a as i64 * self.a as i64;
         let b2: i64 = self.b as i64 * self.b as i64;
         (a2 + b2) as u64
     }
 
+    // Lossy floating companion to norm_squared: the Euclidean length |x|
+    #[cfg(feature = "std")]
+    pub fn magnitude(self) -> f64 {
+        (self.norm_squared() as f64).sqrt()
+    }
+
+    // Polarization of the norm form: N(a + b) - N(a) - N(b) = 2 Re(a conj(b)),
+    // twice the Euclidean inner product, so it equals 2 * lattice_dot.
+    // Accumulated in i64 like the HInt and OInt versions, so it cannot overflow.
+    pub fn bilinear_form(a: Self, b: Self) -> i64 {
+        2 * (a.a as i64 * b.a as i64 + a.b as i64 * b.b as i64)
+    }
+
+    pub fn real_part(self) -> f64 {
+        self.a as f64
+    }
+
+    // Re(x) as a reduced (numerator, denominator) pair, the shape HInt and
+    // OInt need for their half-integer real parts
+    pub fn scalar(self) -> (i64, u64) {
+        (self.a as i64, 1)
+    }
+
+    // Reduced trace x + conj(x) = 2·Re(x), in i64 so that it cannot overflow
+    pub fn trace(self) -> i64 {
+        2 * self.a as i64
+    }
+
+    // Norm of the pure imaginary part: N(x) = Re(x)² + imaginary_norm_squared,
+    // as a reduced (numerator, denominator) pair like scalar
+    pub fn imaginary_norm_squared(self) -> (u64, u64) {
+        ((self.b as i64 * self.b as i64) as u64, 1)
+    }
+
     pub fn associates(self) -> [Self; 4] {
         [
             self,
@@ -106,6 +247,122 @@ impl CInt {
         assocs[0]
     }
 
+    // The unique representative of {u * self} over the four units. For Z[i]
+    // this is normalize(): the associate with a > 0 and b >= 0, i.e. in the
+    // half-open first quadrant; zero maps to itself.
+    pub fn canonical_associate(self) -> Self {
+        self.normalize()
+    }
+
+    // True when other = u * self for one of the four units
+    pub fn eq_up_to_unit(self, other: Self) -> bool {
+        self.canonical_associate() == other.canonical_associate()
+    }
+
+    // unit * self: a rotation of Z² by a multiple of 90°, so one of the four
+    // lattice symmetries that fix the origin without reflecting
+    pub fn rotate(self, unit: Self) -> Result<Self, CIntError> {
+        if !unit.is_unit() {
+            return Err(CIntError::NotAUnit);
+        }
+        Ok(unit * self)
+    }
+
+    // rotate() applied to every point
+    #[cfg(feature = "std")]
+    pub fn apply_symmetry(points: &[Self], unit: Self) -> Result<Vec<Self>, CIntError> {
+        if !unit.is_unit() {
+            return Err(CIntError::NotAUnit);
+        }
+        Ok(points.iter().map(|&p| unit * p).collect())
+    }
+
+    // Factorization type from the norm: N = 2 is ramified, a prime norm
+    // p ≡ 1 mod 4 is split, and a rational prime p ≡ 3 mod 4 (up to units)
+    // stays prime with norm p²
+    pub fn classify(self) -> GaussianKind {
+        let norm = self.norm_squared();
+        if norm == 0 {
+            return GaussianKind::Zero;
+        }
+        if norm == 1 {
+            return GaussianKind::Unit;
+        }
+        if norm == 2 {
+            return GaussianKind::RamifiedPrime;
+        }
+        if num_utils::is_prime(norm) {
+            return GaussianKind::SplitPrime;
+        }
+
+        // Only associates of a rational integer can be inert
+        if self.a == 0 || self.b == 0 {
+            let p = (self.a as i64 + self.b as i64).unsigned_abs();
+            if p % 4 == 3 && num_utils::is_prime(p) {
+                return GaussianKind::InertPrime;
+            }
+        }
+
+        GaussianKind::Composite
+    }
+
+    pub fn is_prime(self) -> bool {
+        matches!(
+            self.classify(),
+            GaussianKind::RamifiedPrime | GaussianKind::InertPrime | GaussianKind::SplitPrime
+        )
+    }
+
+    // One representative per associate class of the Gaussian primes with
+    // norm <= norm_bound, each in normalized form (a > 0, b >= 0), ordered
+    // by norm and then by a. Scans the quarter disc, so the cost grows
+    // linearly with the bound.
+    #[cfg(feature = "std")]
+    pub fn gaussian_primes_up_to(norm_bound: u64) -> Vec<CInt> {
+        let bound = norm_bound.min(i32::MAX as u64 * i32::MAX as u64);
+        let mut primes = Vec::new();
+
+        let mut a: u64 = 1;
+        while a * a <= bound {
+            let mut b: u64 = 0;
+            while a * a + b * b <= bound {
+                let z = CInt::new(a as i32, b as i32);
+                if z.is_prime() {
+                    primes.push(z);
+                }
+                b += 1;
+            }
+            a += 1;
+        }
+
+        primes.sort_by_key(|z| (z.norm_squared(), z.a));
+        primes
+    }
+
+    // Nearest Gaussian integer to r(cos θ + i sin θ), θ in radians
+    #[cfg(feature = "std")]
+    pub fn from_polar_rounded(r: f64, theta: f64) -> Self {
+        CInt::closest_lattice_point((r * theta.cos(), r * theta.sin()))
+    }
+
+    // Parse comma-separated integer coordinates, e.g. "3,-4"
+    #[cfg(feature = "std")]
+    pub fn from_coords_str(s: &str) -> Result<Self, CIntError> {
+        let v = s.split(',')
+            .map(|x| x.trim().parse::<i32>())
+            .collect::<Result<Vec<i32>, _>>()
+            .map_err(|_| CIntError::InvalidFormat)?;
+        if v.len() != 2 {
+            return Err(CIntError::InvalidFormat);
+        }
+        Ok(CInt::new(v[0], v[1]))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_coords_str(self) -> String {
+        format!("{},{}", self.a, self.b)
+    }
+
     pub fn div_rem(self, d: Self) -> Result<(Self, Self), CIntError> {
         if d.is_zero() {
             return Err(CIntError::DivisionByZero);
@@ -116,18 +373,43 @@ impl CInt {
         let num_a = self.a as i64 * d_conj.a as i64 - self.b as i64 * d_conj.b as i64;
         let num_b = self.a as i64 * d_conj.b as i64 + self.b as i64 * d_conj.a as i64;
 
-        let q_real_f = num_a as f64 / norm_d as f64;
-        let q_imag_f = num_b as f64 / norm_d as f64;
-
-        let q_real = q_real_f.round() as i32;
-        let q_imag = q_imag_f.round() as i32;
+        // Try both integer neighbours of each quotient coordinate and keep the
+        // smallest remainder, compared exactly in i64 (so a candidate that
+        // loses cannot overflow). Off a tie the nearest pair wins; on a tie at
+        // x.5 the candidates are equidistant and the first, rounded away from
+        // zero, is kept.
+        let (da, db) = (d.a as i64, d.b as i64);
+        let mut best = (0i64, 0i64, 0i64, 0i64, u64::MAX);
+        for q_a in num_utils::nearest_pair(num_a, norm_d) {
+            for q_b in num_utils::nearest_pair(num_b, norm_d) {
+                let r_a = self.a as i64 - (q_a * da - q_b * db);
+                let r_b = self.b as i64 - (q_a * db + q_b * da);
+                let norm = (r_a * r_a + r_b * r_b) as u64;
+                if norm < best.4 {
+                    best = (q_a, q_b, r_a, r_b, norm);
+                }
+            }
+        }
 
-        let q = CInt::new(q_real, q_imag);
-        let r = self - (q * d);
+        // The quotient can leave i32 (i32::MIN / -1); report that instead of
+        // wrapping
+        let narrow = |x: i64| i32::try_from(x).map_err(|_| CIntError::Overflow);
+        let q = CInt::new(narrow(best.0)?, narrow(best.1)?);
+        let r = CInt::new(narrow(best.2)?, narrow(best.3)?);
+        debug_assert!(self.div_rem_holds(d, q, r), "CInt div_rem: self != q * d + r");
 
         Ok((q, r))
     }
 
+    // self == q * d + r, evaluated in i64 so that a quotient or remainder
+    // that wrapped when narrowed to i32 is caught rather than cancelled out
+    fn div_rem_holds(self, d: Self, q: Self, r: Self) -> bool {
+        let (qa, qb, da, db) = (q.a as i64, q.b as i64, d.a as i64, d.b as i64);
+        qa * da - qb * db + r.a as i64 == self.a as i64
+            && qa * db + qb * da + r.b as i64 == self.b as i64
+    }
+
+    #[cfg(feature = "std")]
     pub fn div_exact(self, d: Self) -> Result<Self, CIntError> {
         let (q, r) = self.div_rem(d)?;
         if r.is_zero() {
@@ -176,9 +458,40 @@ impl CInt {
         Ok(CIFraction { num: conj, den })
     }
 
+    // self^exp by repeated squaring
+    pub fn pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut acc = CInt::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc * base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base * base;
+            }
+        }
+        acc
+    }
+
+    // self^exp in the field of fractions, reduced: a negative exponent raises
+    // inv_fraction() to |exp|, and exp == 0 gives 1/1
+    pub fn pow_frac(self, exp: i32) -> Result<CIFraction, CIntError> {
+        let base = if exp < 0 { self.inv_fraction()? } else { CIFraction::from(self) };
+        let mut acc = CIFraction::from(CInt::one());
+        for _ in 0..exp.unsigned_abs() {
+            acc = acc * base;
+        }
+        Ok(CInt::reduce_fraction(acc))
+    }
+
+    // Lowest terms: num and den divided by their common integer factor, and
+    // 0/d becomes 0/1. That form is already unique per value: den is unsigned,
+    // so there is no sign to move, and multiplying num by a unit would change
+    // the value rather than its representation.
     pub fn reduce_fraction(frac: CIFraction) -> CIFraction {
-        let a_abs = frac.num.a.abs() as u64;
-        let b_abs = frac.num.b.abs() as u64;
+        let a_abs = frac.num.a.unsigned_abs() as u64;
+        let b_abs = frac.num.b.unsigned_abs() as u64;
         let g1 = num_utils::integer_gcd(a_abs, b_abs);
         let g = num_utils::integer_gcd(g1, frac.den);
 
@@ -196,6 +509,97 @@ impl CInt {
         }
     }
 
+    // Canonical residue of self in Z[i]/(modulus). With g = gcd(a, b) for
+    // modulus a + bi, the residues are x + yi with 0 <= y < g and
+    // 0 <= x < N/g, one per class, so there are exactly N(modulus) of them.
+    // Panics if the modulus is zero or, once N/g passes i32::MAX, if x does
+    // not fit; checked_rem_mod reports both as errors.
+    pub fn rem_mod(self, modulus: Self) -> Self {
+        self.checked_rem_mod(modulus).expect("CInt residue overflow")
+    }
+
+    // rem_mod, with DivisionByZero for a zero modulus and Overflow when the
+    // canonical residue does not fit i32
+    pub fn checked_rem_mod(self, modulus: Self) -> Result<Self, CIntError> {
+        Self::reduce_mod(self.a as i64, self.b as i64, modulus)
+    }
+
+    // (a * b).rem_mod(modulus), with the product kept in i64. Panics where
+    // rem_mod does.
+    pub fn mul_mod(a: Self, b: Self, modulus: Self) -> Self {
+        Self::checked_mul_mod(a, b, modulus).expect("CInt residue overflow")
+    }
+
+    // mul_mod with the errors of checked_rem_mod
+    pub fn checked_mul_mod(a: Self, b: Self, modulus: Self) -> Result<Self, CIntError> {
+        let (a, b) = (a.checked_rem_mod(modulus)?, b.checked_rem_mod(modulus)?);
+        let real = a.a as i64 * b.a as i64 - a.b as i64 * b.b as i64;
+        let imag = a.a as i64 * b.b as i64 + a.b as i64 * b.a as i64;
+        Self::reduce_mod(real, imag, modulus)
+    }
+
+    // Inverse in Z[i]/(modulus) from the Bézout cofactor of xgcd, as a
+    // canonical residue. NoInverse unless gcd(self, modulus) is a unit.
+    #[cfg(feature = "std")]
+    pub fn inv_mod(self, modulus: Self) -> Result<Self, CIntError> {
+        if modulus.is_zero() {
+            return Err(CIntError::DivisionByZero);
+        }
+
+        let (g, s, t) = Self::xgcd(self, modulus);
+        if !g.is_unit() {
+            return Err(CIntError::NoInverse);
+        }
+
+        // s * self + t * modulus is a unit u (before normalization), so s / u
+        // inverts self; 1/u = conj(u) for units
+        let u = s * self + t * modulus;
+        Self::checked_mul_mod(s, u.conj(), modulus)
+    }
+
+    // Solve x ≡ r_k (mod m_k) for (r_k, m_k) pairs with pairwise coprime
+    // moduli. Returns the canonical residue modulo the product of the moduli;
+    // NoInverse if two moduli share a factor.
+    #[cfg(feature = "std")]
+    pub fn crt(residues: &[(Self, Self)]) -> Result<Self, CIntError> {
+        let mut x = Self::zero();
+        let mut product = Self::one();
+
+        for &(r, m) in residues {
+            // x + product * k hits r mod m for k = (r - x) / product mod m
+            let inv = product.inv_mod(m)?;
+            let k = Self::checked_mul_mod(r - x, inv, m)?;
+            x = x + product * k;
+            product = product * m;
+            x = x.checked_rem_mod(product)?;
+        }
+
+        Ok(x)
+    }
+
+    fn reduce_mod(x: i64, y: i64, modulus: Self) -> Result<Self, CIntError> {
+        if modulus.is_zero() {
+            return Err(CIntError::DivisionByZero);
+        }
+
+        // The ideal (modulus) as a lattice in Z² has basis (N/g, 0), (t, g).
+        // N reaches 2^63, so the lattice arithmetic runs in i128.
+        let (g, u, v) = num_utils::integer_xgcd(modulus.b as i64, modulus.a as i64);
+        let (a, b) = (modulus.a as i128, modulus.b as i128);
+        let (g, u, v) = (g as i128, u as i128, v as i128);
+        let period = (a * a + b * b) / g;
+        let t = (u * a - v * b).rem_euclid(period);
+
+        let k = (y as i128).div_euclid(g);
+        let y = y as i128 - k * g;
+        let x = (x as i128 - k * t).rem_euclid(period);
+
+        // 0 <= y < g always fits; x < N/g need not
+        let x = i32::try_from(x).map_err(|_| CIntError::Overflow)?;
+        Ok(Self::new(x, y as i32))
+    }
+
+    #[cfg(feature = "std")]
     pub fn gcd(a: Self, b: Self) -> Self {
         let mut x = a.normalize();
         let mut y = b.normalize();
@@ -207,6 +611,21 @@ impl CInt {
         x.normalize()
     }
 
+    // gcd of the whole slice, folded left to right: zero for an empty slice,
+    // the normalized element for a single one. Stops early at a unit.
+    #[cfg(feature = "std")]
+    pub fn gcd_many(xs: &[Self]) -> Self {
+        let mut acc = CInt::zero();
+        for &x in xs {
+            acc = CInt::gcd(acc, x);
+            if acc.is_unit() {
+                break;
+            }
+        }
+        acc
+    }
+
+    #[cfg(feature = "std")]
     pub fn xgcd(a: Self, b: Self) -> (Self, Self, Self) {
         if b.is_zero() {
             return (a.normalize(), Self::one(), Self::zero());
@@ -237,12 +656,205 @@ impl CInt {
     }
 }
 
+impl Default for CInt {
+    fn default() -> Self {
+        CInt::zero()
+    }
+}
+
+impl PartialEq for CIFraction {
+    fn eq(&self, other: &Self) -> bool {
+        let (x, y) = (self.normalized(), other.normalized());
+        x.num == y.num && x.den == y.den
+    }
+}
+
+impl core::hash::Hash for CIFraction {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        let x = self.normalized();
+        x.num.hash(state);
+        x.den.hash(state);
+    }
+}
+
+impl CIFraction {
+    // Canonical lowest-terms form
+    pub fn normalized(self) -> Self {
+        CInt::reduce_fraction(self)
+    }
+
+    // N(num) / den² as a reduced (numerator, denominator) pair; exact, with
+    // the intermediate den² held in u128
+    pub fn norm_squared(self) -> (u64, u64) {
+        reduce_ratio(self.num.norm_squared() as u128, self.den as u128 * self.den as u128)
+    }
+
+    // Real and imaginary parts as separate (numerator, denominator) pairs,
+    // each in lowest terms on its own, so the denominators can differ
+    pub fn to_rational_parts(self) -> ((i64, u64), (i64, u64)) {
+        let part = |x: i32| {
+            let g = num_utils::integer_gcd(x.unsigned_abs() as u64, self.den).max(1);
+            (x as i64 / g as i64, self.den / g)
+        };
+        (part(self.num.a), part(self.num.b))
+    }
+
+    // Partial quotients q0, q1, ... with num/den = q0 + 1/(q1 + 1/(q2 + ...)),
+    // from the Euclidean algorithm on (num, den). Empty when den is zero,
+    // Overflow when den does not fit an i32 component.
+    #[cfg(feature = "std")]
+    pub fn continued_fraction(self) -> Result<Vec<CInt>, CIntError> {
+        let den = i32::try_from(self.den).map_err(|_| CIntError::Overflow)?;
+        let mut quotients = Vec::new();
+        let mut a = self.num;
+        let mut b = CInt::new(den, 0);
+
+        while let Ok((q, r)) = a.div_rem(b) {
+            quotients.push(q);
+            a = b;
+            b = r;
+        }
+
+        Ok(quotients)
+    }
+
+    // Fold partial quotients back into a reduced fraction. InvalidFormat for
+    // an empty list, DivisionByZero if an intermediate convergent vanishes.
+    #[cfg(feature = "std")]
+    pub fn from_continued_fraction(quotients: &[CInt]) -> Result<Self, CIntError> {
+        let (&last, rest) = quotients.split_last().ok_or(CIntError::InvalidFormat)?;
+
+        // Evaluate from the innermost term: q + 1/(h/k) = (q*h + k)/h
+        let (mut h, mut k) = (last, CInt::one());
+        for &q in rest.iter().rev() {
+            if h.is_zero() {
+                return Err(CIntError::DivisionByZero);
+            }
+            (h, k) = (q * h + k, h);
+        }
+
+        h.div_to_fraction(k).map(CIFraction::normalized)
+    }
+
+    // The last continued-fraction convergent whose reduced denominator is at
+    // most max_den (treated as 1 when zero). The first convergent, the nearest
+    // Gaussian integer, always qualifies. A zero denominator comes back as is;
+    // Overflow when den does not fit an i32 component.
+    //
+    // Convergents are the best approximations available from the expansion,
+    // but not necessarily over every fraction with a small enough denominator:
+    // a common rational denominator approximates the real and imaginary parts
+    // simultaneously, which continued fractions do not optimize for.
+    #[cfg(feature = "std")]
+    pub fn best_approximation(self, max_den: u64) -> Result<Self, CIntError> {
+        let quotients = self.continued_fraction()?;
+        Ok((1..=quotients.len())
+            .rev()
+            .filter_map(|n| Self::from_continued_fraction(&quotients[..n]).ok())
+            .find(|c| c.den <= max_den.max(1))
+            .unwrap_or(self))
+    }
+}
+
+impl Default for CIFraction {
+    fn default() -> Self {
+        CIFraction {
+            num: CInt::zero(),
+            den: 1,
+        }
+    }
+}
+
+impl From<(i32, i32)> for CInt {
+    fn from((a, b): (i32, i32)) -> Self {
+        CInt::new(a, b)
+    }
+}
+
+impl From<[i32; 2]> for CInt {
+    fn from([a, b]: [i32; 2]) -> Self {
+        CInt::new(a, b)
+    }
+}
+
+impl From<CInt> for CIFraction {
+    fn from(x: CInt) -> Self {
+        CIFraction { num: x, den: 1 }
+    }
+}
+
+impl CIFraction {
+    // Rewrite over the common denominator lcm(self.den, other.den)
+    fn over_common_den(self, other: Self) -> (CInt, CInt, u64) {
+        let g = num_utils::integer_gcd(self.den, other.den);
+        let lcm = self.den / g * other.den;
+        let scale = |num: CInt, k: u64| {
+            i32::try_from(k).ok()
+                .and_then(|k| num.checked_scale(k))
+                .expect("CIFraction denominator overflow")
+        };
+        (scale(self.num, lcm / self.den), scale(other.num, lcm / other.den), lcm)
+    }
+}
+
+impl Add for CIFraction {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let (a, b, den) = self.over_common_den(rhs);
+        CInt::reduce_fraction(CIFraction { num: a + b, den })
+    }
+}
+
+impl Sub for CIFraction {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        let (a, b, den) = self.over_common_den(rhs);
+        CInt::reduce_fraction(CIFraction { num: a - b, den })
+    }
+}
+
+impl Mul for CIFraction {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let den = self.den.checked_mul(rhs.den).expect("CIFraction denominator overflow");
+        CInt::reduce_fraction(CIFraction { num: self.num * rhs.num, den })
+    }
+}
+
+impl CInt {
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(CInt::new(self.a.checked_add(rhs.a)?, self.b.checked_add(rhs.b)?))
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(CInt::new(self.a.checked_sub(rhs.a)?, self.b.checked_sub(rhs.b)?))
+    }
+
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        CInt::new(self.a.wrapping_add(rhs.a), self.b.wrapping_add(rhs.b))
+    }
+
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        CInt::new(self.a.wrapping_sub(rhs.a), self.b.wrapping_sub(rhs.b))
+    }
+
+    // n * self by scaling each component, much cheaper than multiplying by n
+    // as a ring element; overflows like Add
+    pub fn scale(self, n: i32) -> Self {
+        CInt::new(self.a * n, self.b * n)
+    }
+
+    pub fn checked_scale(self, n: i32) -> Option<Self> {
+        Some(CInt::new(self.a.checked_mul(n)?, self.b.checked_mul(n)?))
+    }
+}
+
 impl Add for CInt {
     type Output = Self;
     fn add(self, rhs: Self) -> Self {
         Self {
-            a: self.a.wrapping_add(rhs.a),
-            b: self.b.wrapping_add(rhs.b),
+            a: self.a + rhs.a,
+            b: self.b + rhs.b,
         }
     }
 }
@@ -251,8 +863,8 @@ impl Sub for CInt {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self {
         Self {
-            a: self.a.wrapping_sub(rhs.a),
-            b: self.b.wrapping_sub(rhs.b),
+            a: self.a - rhs.a,
+            b: self.b - rhs.b,
         }
     }
 }
@@ -275,13 +887,86 @@ impl Mul for CInt {
     }
 }
 
+impl Div for CInt {
+    type Output = CIFraction;
+    // Panics on a zero divisor; div_to_fraction is the checked form
+    fn div(self, rhs: Self) -> CIFraction {
+        self.div_to_fraction(rhs).expect("CInt division by zero")
+    }
+}
+
 impl Neg for CInt {
     type Output = Self;
     fn neg(self) -> Self {
         Self {
-            a: self.a.wrapping_neg(),
-            b: self.b.wrapping_neg(),
+            a: -self.a,
+            b: -self.b,
+        }
+    }
+}
+
+// Component access by position: 0 is the real part, 1 the imaginary part;
+// larger indices panic
+impl Index<usize> for CInt {
+    type Output = i32;
+    fn index(&self, index: usize) -> &i32 {
+        match index {
+            0 => &self.a,
+            1 => &self.b,
+            _ => panic!("CInt component index out of range: {}", index),
         }
     }
 }
 
+impl IndexMut<usize> for CInt {
+    fn index_mut(&mut self, index: usize) -> &mut i32 {
+        match index {
+            0 => &mut self.a,
+            1 => &mut self.b,
+            _ => panic!("CInt component index out of range: {}", index),
+        }
+    }
+}
+
+
+// ========================================================================
+// num-complex interop
+// ========================================================================
+
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+#[cfg(feature = "num-complex")]
+impl CInt {
+    pub fn to_complex(self) -> Complex<f64> {
+        Complex::new(self.a as f64, self.b as f64)
+    }
+
+    // Both parts within eps of c, for comparing against float round-trips
+    pub fn approx_eq_complex(self, c: Complex<f64>, eps: f64) -> bool {
+        let z = self.to_complex();
+        (z.re - c.re).abs() <= eps && (z.im - c.im).abs() <= eps
+    }
+
+    // Nearest Gaussian integer together with the rounding error |c - z|
+    pub fn from_complex_rounded(c: Complex<f64>) -> Result<(Self, f64), CIntError> {
+        let (re, im) = (c.re.round(), c.im.round());
+        let range = i32::MIN as f64..=i32::MAX as f64;
+        if !range.contains(&re) || !range.contains(&im) {
+            return Err(CIntError::Overflow);
+        }
+
+        let z = CInt::new(re as i32, im as i32);
+        Ok((z, (c - z.to_complex()).norm()))
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl TryFrom<Complex<f64>> for CInt {
+    type Error = CIntError;
+
+    // Rounds to the nearest Gaussian integer; fails for NaN/infinite/out-of-range input
+    fn try_from(c: Complex<f64>) -> Result<Self, CIntError> {
+        CInt::from_complex_rounded(c).map(|(z, _)| z)
+    }
+}
@@ -1,9 +1,10 @@
 // src/display.rs
 
 use crate::types::cint::{CInt, CIFraction};
+use crate::types::eint::EInt;
 use crate::types::hint::{HInt, HIFraction};
 use crate::types::oint::{OInt, OIFraction};
-use std::fmt;
+use core::fmt::{self, Write};
 
 // ========================================================================
 // CINT (Complex Integers) Display
@@ -11,13 +12,23 @@ use std::fmt;
 
 impl fmt::Display for CInt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} + {}i", self.a, self.b)
+        pad_with(f, |w| write!(w, "{} + {}i", self.a, self.b))
     }
 }
 
 impl fmt::Display for CIFraction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({}) / {}", self.num, self.den)
+        pad_with(f, |w| write!(w, "({}) / {}", self.num, self.den))
+    }
+}
+
+// ========================================================================
+// EINT (Eisenstein Integers) Display
+// ========================================================================
+
+impl fmt::Display for EInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad_with(f, |w| write!(w, "{} + {}ω", self.a, self.b))
     }
 }
 
@@ -27,25 +38,17 @@ impl fmt::Display for CIFraction {
 
 impl fmt::Display for HInt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (a, b, c, d) = self.to_float_components();
-        
-        write!(f, "{}", format_component(a, "", true))?;
-        write!(f, "{}", format_component(b, "i", false))?;
-        write!(f, "{}", format_component(c, "j", false))?;
-        write!(f, "{}", format_component(d, "k", false))
+        pad_with(f, |w| write_terms(w, &self.raw_components(), &HINT_UNITS))
     }
 }
 
 impl fmt::Display for HIFraction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (a, b, c, d) = self.num.to_float_components();
-        
-        write!(f, "(")?;
-        write!(f, "{}", format_component(a, "", true))?;
-        write!(f, "{}", format_component(b, "i", false))?;
-        write!(f, "{}", format_component(c, "j", false))?;
-        write!(f, "{}", format_component(d, "k", false))?;
-        write!(f, ") / {}", self.den)
+        pad_with(f, |w| {
+            write!(w, "(")?;
+            write_terms(w, &self.num.raw_components(), &HINT_UNITS)?;
+            write!(w, ") / {}", self.den)
+        })
     }
 }
 
@@ -55,33 +58,17 @@ impl fmt::Display for HIFraction {
 
 impl fmt::Display for OInt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (a, b, c, d, e, f_val, g, h) = self.to_float_components();
-        
-        write!(f, "{}", format_component(a, "", true))?;
-        write!(f, "{}", format_component(b, "e₁", false))?;
-        write!(f, "{}", format_component(c, "e₂", false))?;
-        write!(f, "{}", format_component(d, "e₃", false))?;
-        write!(f, "{}", format_component(e, "e₄", false))?;
-        write!(f, "{}", format_component(f_val, "e₅", false))?;
-        write!(f, "{}", format_component(g, "e₆", false))?;
-        write!(f, "{}", format_component(h, "e₇", false))
+        pad_with(f, |w| write_terms(w, &self.raw_components(), &OINT_UNITS))
     }
 }
 
 impl fmt::Display for OIFraction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (a, b, c, d, e, f_val, g, h) = self.num.to_float_components();
-        
-        write!(f, "(")?;
-        write!(f, "{}", format_component(a, "", true))?;
-        write!(f, "{}", format_component(b, "e₁", false))?;
-        write!(f, "{}", format_component(c, "e₂", false))?;
-        write!(f, "{}", format_component(d, "e₃", false))?;
-        write!(f, "{}", format_component(e, "e₄", false))?;
-        write!(f, "{}", format_component(f_val, "e₅", false))?;
-        write!(f, "{}", format_component(g, "e₆", false))?;
-        write!(f, "{}", format_component(h, "e₇", false))?;
-        write!(f, ") / {}", self.den)
+        pad_with(f, |w| {
+            write!(w, "(")?;
+            write_terms(w, &self.num.raw_components(), &OINT_UNITS)?;
+            write!(w, ") / {}", self.den)
+        })
     }
 }
 
@@ -89,66 +76,219 @@ impl fmt::Display for OIFraction {
 // Helper function for formatting components
 // ========================================================================
 
-fn format_component(val: f64, unit: &str, is_first: bool) -> String {
-    // Handle zero components
-    if val == 0.0 && !is_first {
-        return String::new();
-    }
-    
-    // Determine if we need to show as fraction
-    let is_fraction = val.fract() != 0.0;
-    
-    // Format the number
-    let num_str = if is_fraction {
-        if (val.fract().abs() - 0.5).abs() < 0.0001 {
-            // It's a .5 fraction
-            let whole = val.trunc() as i32;
-            if whole == 0 {
-                "1/2".to_string()
-            } else {
-                format!("{} + 1/2", whole)
+// Honors width, fill, alignment (left by default, as for strings) and the
+// `+` flag around whatever `body` writes. The body runs twice, first into a
+// counter for the padding, so no buffer is allocated and this works without std.
+fn pad_with(f: &mut fmt::Formatter<'_>, body: impl Fn(&mut dyn fmt::Write) -> fmt::Result) -> fmt::Result {
+    let mut measure = Measure { chars: 0, first: None };
+    body(&mut measure)?;
+
+    let plus = f.sign_plus() && measure.first != Some('-');
+    let len = measure.chars + plus as usize;
+    let padding = f.width().map_or(0, |w| w.saturating_sub(len));
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    if plus {
+        f.write_char('+')?;
+    }
+    body(f)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+// Counts the chars written, remembering the first for the `+` flag
+struct Measure {
+    chars: usize,
+    first: Option<char>,
+}
+
+impl fmt::Write for Measure {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.first.is_none() {
+            self.first = s.chars().next();
+        }
+        self.chars += s.chars().count();
+        Ok(())
+    }
+}
+
+const HINT_UNITS: [&str; 4] = ["", "i", "j", "k"];
+const OINT_UNITS: [&str; 8] = ["", "e₁", "e₂", "e₃", "e₄", "e₅", "e₆", "e₇"];
+
+// Nonzero terms only, so the first one carries a bare "-" rather than a
+// " + "/" - " separator, and an all-zero value prints "0". A coefficient of
+// ±1 on a basis element is left implicit ("e₇", not "1e₇").
+fn write_terms(f: &mut dyn fmt::Write, raws: &[i32], units: &[&str]) -> fmt::Result {
+    let mut first = true;
+    for (&raw, &unit) in raws.iter().zip(units) {
+        if raw == 0 {
+            continue;
+        }
+        if first {
+            if raw < 0 {
+                write!(f, "-")?;
             }
         } else {
-            format!("{}", val)
+            write!(f, "{}", if raw > 0 { " + " } else { " - " })?;
         }
+        write_magnitude(f, (raw as i64).abs(), unit)?;
+        first = false;
+    }
+
+    if first {
+        write!(f, "0")?;
+    }
+    Ok(())
+}
+
+// `mag` is the *2 stored magnitude; odd values print exactly as "mag/2",
+// without going through f64
+fn write_magnitude(f: &mut dyn fmt::Write, mag: i64, unit: &str) -> fmt::Result {
+    if mag == 2 && !unit.is_empty() {
+        write!(f, "{}", unit)
+    } else if mag % 2 == 0 {
+        write!(f, "{}{}", mag / 2, unit)
     } else {
-        format!("{}", val as i32)
-    };
-    
-    // Build the term
-    if is_first {
-        if unit.is_empty() {
-            num_str
-        } else {
-            format!("{}{}", num_str, unit)
+        write!(f, "{}/2{}", mag, unit)
+    }
+}
+
+// ========================================================================
+// LaTeX output
+// ========================================================================
+
+#[cfg(feature = "std")]
+impl CInt {
+    pub fn to_latex(&self) -> String {
+        latex_terms(&[self.a, self.b], &["", "i"], false)
+    }
+}
+
+#[cfg(feature = "std")]
+impl CIFraction {
+    pub fn to_latex(&self) -> String {
+        format!("\\frac{{{}}}{{{}}}", self.num.to_latex(), self.den)
+    }
+}
+
+#[cfg(feature = "std")]
+impl HInt {
+    pub fn to_latex(&self) -> String {
+        latex_terms(
+            &[self.a, self.b, self.c, self.d],
+            &["", "\\mathbf{i}", "\\mathbf{j}", "\\mathbf{k}"],
+            true,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl HIFraction {
+    pub fn to_latex(&self) -> String {
+        format!("\\frac{{{}}}{{{}}}", self.num.to_latex(), self.den)
+    }
+}
+
+#[cfg(feature = "std")]
+impl OInt {
+    pub fn to_latex(&self) -> String {
+        latex_terms(
+            &[self.a, self.b, self.c, self.d, self.e, self.f, self.g, self.h],
+            &["", "e_{1}", "e_{2}", "e_{3}", "e_{4}", "e_{5}", "e_{6}", "e_{7}"],
+            true,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl OIFraction {
+    pub fn to_latex(&self) -> String {
+        format!("\\frac{{{}}}{{{}}}", self.num.to_latex(), self.den)
+    }
+}
+
+// Join the nonzero terms; `halves` means the values use the *2 storage
+#[cfg(feature = "std")]
+fn latex_terms(values: &[i32], units: &[&str], halves: bool) -> String {
+    let mut out = String::new();
+
+    for (&val, unit) in values.iter().zip(units) {
+        if val == 0 {
+            continue;
         }
-    } else {
-        let abs_val = val.abs();
-        let abs_is_fraction = abs_val.fract() != 0.0;
-        
-        let abs_str = if abs_is_fraction {
-            if (abs_val.fract().abs() - 0.5).abs() < 0.0001 {
-                let whole = abs_val.trunc() as i32;
-                if whole == 0 {
-                    "1/2".to_string()
-                } else {
-                    format!("{} + 1/2", whole)
-                }
-            } else {
-                format!("{}", abs_val)
-            }
+
+        let mag = val.unsigned_abs();
+        let coeff = if !halves {
+            mag.to_string()
+        } else if mag % 2 == 0 {
+            (mag / 2).to_string()
         } else {
-            format!("{}", abs_val as i32)
+            format!("\\frac{{{}}}{{2}}", mag)
         };
-        
-        let sign = if val >= 0.0 { " + " } else { " - " };
-        
-        if unit.is_empty() {
-            format!("{}{}", sign, abs_str)
+
+        if out.is_empty() {
+            if val < 0 {
+                out.push('-');
+            }
         } else {
-            format!("{}{}{}", sign, abs_str, unit)
+            out.push_str(if val < 0 { " - " } else { " + " });
+        }
+
+        if coeff != "1" || unit.is_empty() {
+            out.push_str(&coeff);
         }
+        out.push_str(unit);
     }
+
+    if out.is_empty() {
+        "0".to_string()
+    } else {
+        out
+    }
+}
+
+// ========================================================================
+// Raw storage dump
+// ========================================================================
+
+// The stored (*2) integers, their parity (even: integer coordinates, odd:
+// half-integer, mixed: not a valid value) and the Display form, e.g.
+// "HInt{raw:[2,2,2,2], parity:even, value:1 + i + j + k}"
+#[cfg(feature = "std")]
+impl HInt {
+    pub fn raw_debug(self) -> String {
+        raw_dump("HInt", &self.raw_components(), &self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl OInt {
+    pub fn raw_debug(self) -> String {
+        raw_dump("OInt", &self.raw_components(), &self)
+    }
+}
+
+#[cfg(feature = "std")]
+fn raw_dump(name: &str, raws: &[i32], value: &dyn fmt::Display) -> String {
+    let parity = if raws.iter().all(|x| x % 2 == 0) {
+        "even"
+    } else if raws.iter().all(|x| x % 2 != 0) {
+        "odd"
+    } else {
+        "mixed"
+    };
+    let raw = raws.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
+    format!("{}{{raw:[{}], parity:{}, value:{}}}", name, raw, parity, value)
 }
 
 // ========================================================================
@@ -167,6 +307,12 @@ impl fmt::Debug for CIFraction {
     }
 }
 
+impl fmt::Debug for EInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EInt({})", self)
+    }
+}
+
 impl fmt::Debug for HInt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "HInt({})", self)
@@ -0,0 +1,236 @@
+use core::ops::{Add, Sub, Mul, Neg};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EIntError {
+    Overflow,
+    DivisionByZero,
+    NotDivisible,
+    NoInverse,
+}
+
+// Eisenstein integer a + bω with ω = e^(2πi/3), so ω² = -1 - ω
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct EInt {
+    pub a: i32,  // coefficient of 1
+    pub b: i32,  // coefficient of ω
+}
+
+impl EInt {
+    pub const fn new(a: i32, b: i32) -> Self {
+        EInt { a, b }
+    }
+
+    pub const fn zero() -> Self {
+        EInt::new(0, 0)
+    }
+
+    pub const fn one() -> Self {
+        EInt::new(1, 0)
+    }
+
+    pub const fn omega() -> Self {
+        EInt::new(0, 1)
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.a == 0 && self.b == 0
+    }
+
+    pub fn is_unit(self) -> bool {
+        self.norm_squared() == 1
+    }
+
+    // conj(ω) = ω² = -1 - ω, so conj(a + bω) = (a - b) - bω
+    pub fn conj(self) -> Self {
+        EInt::new(self.a - self.b, -self.b)
+    }
+
+    // conj() in place; not a plain sign flip in the 1, ω basis
+    pub fn conj_mut(&mut self) {
+        self.a -= self.b;
+        self.b = -self.b;
+    }
+
+    pub fn norm_squared(self) -> u64 {
+        let a = self.a as i64;
+        let b = self.b as i64;
+        (a * a - a * b + b * b) as u64
+    }
+
+    // Lossy floating companion to norm_squared: the Euclidean length |x|
+    #[cfg(feature = "std")]
+    pub fn magnitude(self) -> f64 {
+        (self.norm_squared() as f64).sqrt()
+    }
+
+    // |self - other| in the complex plane; the differences need i64 and the
+    // norm form of them i128
+    #[cfg(feature = "std")]
+    pub fn lattice_distance(self, other: Self) -> f64 {
+        let da = self.a as i128 - other.a as i128;
+        let db = self.b as i128 - other.b as i128;
+        ((da * da - da * db + db * db) as f64).sqrt()
+    }
+
+    // The six units ±1, ±ω, ±ω² times self
+    pub fn associates(self) -> [Self; 6] {
+        let w = EInt::omega();
+        let w2 = w * w;
+        [self, -self, self * w, -(self * w), self * w2, -(self * w2)]
+    }
+
+    // Canonical associate: the one with argument in [0°, 60°), i.e. a > b >= 0
+    pub fn normalize(self) -> Self {
+        if self.is_zero() {
+            return self;
+        }
+
+        for candidate in self.associates() {
+            if candidate.b >= 0 && candidate.a > candidate.b {
+                return candidate;
+            }
+        }
+
+        self
+    }
+
+    #[cfg(feature = "std")]
+    pub fn div_rem(self, d: Self) -> Result<(Self, Self), EIntError> {
+        if d.is_zero() {
+            return Err(EIntError::DivisionByZero);
+        }
+
+        let norm_d = d.norm_squared() as i64;
+        let d_conj = d.conj();
+
+        // self * conj(d) in i64 to avoid overflow before rounding
+        let (a, b) = (self.a as i64, self.b as i64);
+        let (c, e) = (d_conj.a as i64, d_conj.b as i64);
+        let num_a = a * c - b * e;
+        let num_b = a * e + b * c - b * e;
+
+        let q_a = (num_a as f64 / norm_d as f64).round() as i32;
+        let q_b = (num_b as f64 / norm_d as f64).round() as i32;
+
+        let q = EInt::new(q_a, q_b);
+        let r = self - (q * d);
+        debug_assert!(self.div_rem_holds(d, q, r), "EInt div_rem: self != q * d + r");
+
+        Ok((q, r))
+    }
+
+    // self == q * d + r in i64, as for CInt
+    #[cfg(feature = "std")]
+    fn div_rem_holds(self, d: Self, q: Self, r: Self) -> bool {
+        let (qa, qb, da, db) = (q.a as i64, q.b as i64, d.a as i64, d.b as i64);
+        qa * da - qb * db + r.a as i64 == self.a as i64
+            && qa * db + qb * da - qb * db + r.b as i64 == self.b as i64
+    }
+
+    #[cfg(feature = "std")]
+    pub fn div_exact(self, d: Self) -> Result<Self, EIntError> {
+        let (q, r) = self.div_rem(d)?;
+        if r.is_zero() {
+            Ok(q)
+        } else {
+            Err(EIntError::NotDivisible)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn gcd(a: Self, b: Self) -> Self {
+        let mut x = a.normalize();
+        let mut y = b.normalize();
+        while !y.is_zero() {
+            let (_, r) = x.div_rem(y).unwrap();
+            x = y;
+            y = r;
+        }
+        x.normalize()
+    }
+}
+
+impl Default for EInt {
+    fn default() -> Self {
+        EInt::zero()
+    }
+}
+
+impl EInt {
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(EInt::new(self.a.checked_add(rhs.a)?, self.b.checked_add(rhs.b)?))
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(EInt::new(self.a.checked_sub(rhs.a)?, self.b.checked_sub(rhs.b)?))
+    }
+
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        EInt::new(self.a.wrapping_add(rhs.a), self.b.wrapping_add(rhs.b))
+    }
+
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        EInt::new(self.a.wrapping_sub(rhs.a), self.b.wrapping_sub(rhs.b))
+    }
+
+    // n * self by scaling each component, much cheaper than multiplying by n
+    // as a ring element; overflows like Add
+    pub fn scale(self, n: i32) -> Self {
+        EInt::new(self.a * n, self.b * n)
+    }
+
+    pub fn checked_scale(self, n: i32) -> Option<Self> {
+        Some(EInt::new(self.a.checked_mul(n)?, self.b.checked_mul(n)?))
+    }
+}
+
+impl Add for EInt {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            a: self.a + rhs.a,
+            b: self.b + rhs.b,
+        }
+    }
+}
+
+impl Sub for EInt {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            a: self.a - rhs.a,
+            b: self.b - rhs.b,
+        }
+    }
+}
+
+impl Mul for EInt {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        // (a + bω)(c + dω) = (ac - bd) + (ad + bc - bd)ω  using ω² = -1 - ω
+        let bd = self.b as i64 * rhs.b as i64;
+        let real = self.a as i64 * rhs.a as i64 - bd;
+        let omega = self.a as i64 * rhs.b as i64 + self.b as i64 * rhs.a as i64 - bd;
+
+        if real > i32::MAX as i64 || real < i32::MIN as i64 ||
+           omega > i32::MAX as i64 || omega < i32::MIN as i64 {
+            panic!("EInt multiplication overflow");
+        }
+
+        Self {
+            a: real as i32,
+            b: omega as i32,
+        }
+    }
+}
+
+impl Neg for EInt {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            a: -self.a,
+            b: -self.b,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+// Text form of the doubled (*2) storage shared by HInt and OInt: a component
+// is an integer or an integer plus one half
+
+// Parse "3", "-1.5", "0.5" into the doubled storage value
+pub(crate) fn parse(s: &str) -> Option<i32> {
+    let s = s.trim();
+    let (neg, body) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let (whole, frac) = body.split_once('.').unwrap_or((body, ""));
+    if whole.is_empty() || !whole.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let half = match frac.trim_end_matches('0') {
+        "" => 0,
+        "5" => 1,
+        _ => return None,
+    };
+    let doubled = whole.parse::<i32>().ok()?.checked_mul(2)?.checked_add(half)?;
+    Some(if neg { -doubled } else { doubled })
+}
+
+// Inverse of parse: "3", "-1.5", "0.5"
+pub(crate) fn format(x: i32) -> String {
+    if x % 2 == 0 {
+        format!("{}", x / 2)
+    } else {
+        format!("{}", x as f64 / 2.0)
+    }
+}
@@ -1,4 +1,8 @@
-use std::ops::{Add, Sub, Mul, Neg};
+use core::ops::{Add, Sub, Mul, Div, Neg, Index, IndexMut};
+use crate::types::cint::CInt;
+use crate::types::{collect_array, halve, max_abs, read_le, reduce_ratio, write_le};
+#[cfg(feature = "std")]
+use crate::types::halves;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HIntError {
@@ -6,15 +10,22 @@ pub enum HIntError {
     DivisionByZero,
     NotDivisible,
     NoInverse,
+    NotAUnit,
     InvalidHalfInteger,
+    InvalidFormat,
+    NotInSubalgebra,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+// `==` and `Hash` compare the reduced form from `normalized()`, so value-equal
+// fractions such as 2/4 and 1/2 are equal and hash equally
+#[derive(Clone, Copy, Eq)]
 pub struct HIFraction {
     pub num: HInt,
     pub den: u64,
 }
 
+// `==` and `Hash` agree with equality of actual values, since the stored
+// doubling is one-to-one
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(C)]
 pub struct HInt {
@@ -33,11 +44,23 @@ mod num_utils {
         }
         a
     }
+
+    // round(n / d) for d > 0, halves away from zero like f64::round
+    pub fn round_div(n: i64, d: i64) -> i64 {
+        let (n, d) = (n as i128, d as i128);
+        let q = (2 * n.abs() + d) / (2 * d);
+        (if n < 0 { -q } else { q }) as i64
+    }
+
+    // floor(n / d) for d > 0
+    pub fn floor_div(n: i64, d: i64) -> i64 {
+        n.div_euclid(d)
+    }
 }
 
 impl HInt {
     // Create from integers (e.g., new(1,2,3,4) = 1 + 2i + 3j + 4k)
-    pub fn new(a: i32, b: i32, c: i32, d: i32) -> Self {
+    pub const fn new(a: i32, b: i32, c: i32, d: i32) -> Self {
         HInt {
             a: a * 2,
             b: b * 2,
@@ -63,26 +86,88 @@ impl HInt {
         Ok(HInt { a, b, c, d })
     }
 
-    pub fn zero() -> Self {
+    // Set the stored (*2) fields directly, e.g. from external code that already
+    // works in doubled coordinates. Bypasses the same-parity check of from_halves.
+    pub const fn from_raw(a: i32, b: i32, c: i32, d: i32) -> Self {
+        HInt { a, b, c, d }
+    }
+
+    // The stored (*2) fields, as accepted by from_raw
+    pub fn raw_components(self) -> [i32; 4] {
+        [self.a, self.b, self.c, self.d]
+    }
+
+    // Same as raw_components; pairs with from_components for coordinate maps
+    pub fn components(self) -> [i32; 4] {
+        self.raw_components()
+    }
+
+    // The stored (*2) fields as little-endian i32s, matching the #[repr(C)]
+    // layout on LE targets
+    pub fn to_le_bytes(self) -> [u8; 16] {
+        let mut out = [0u8; 16];
+        write_le(&self.raw_components(), &mut out);
+        out
+    }
+
+    // Inverse of to_le_bytes; like from_raw, no parity check
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        let mut c = [0i32; 4];
+        read_le(&bytes, &mut c);
+        HInt::from_raw(c[0], c[1], c[2], c[3])
+    }
+
+    // Exactly four stored (*2) values in the order of components(), checked
+    // for parity like from_halves
+    pub fn from_components<I: IntoIterator<Item = i32>>(iter: I) -> Result<Self, HIntError> {
+        let [a, b, c, d] = collect_array(iter).ok_or(HIntError::InvalidFormat)?;
+        HInt::from_halves(a, b, c, d)
+    }
+
+    pub const fn zero() -> Self {
         HInt::new(0, 0, 0, 0)
     }
 
-    pub fn one() -> Self {
+    pub const fn one() -> Self {
         HInt::new(1, 0, 0, 0)
     }
 
-    pub fn i() -> Self {
+    pub const fn i() -> Self {
         HInt::new(0, 1, 0, 0)
     }
 
-    pub fn j() -> Self {
+    pub const fn j() -> Self {
         HInt::new(0, 0, 1, 0)
     }
 
-    pub fn k() -> Self {
+    pub const fn k() -> Self {
         HInt::new(0, 0, 0, 1)
     }
 
+    pub const UNIT_COUNT: usize = 24;
+
+    // The 24 Hurwitz units by index: ±1, ±i, ±j, ±k (0..8), then the 16
+    // half-integer units (±1 ± i ± j ± k)/2. None past UNIT_COUNT.
+    pub fn unit(index: usize) -> Option<Self> {
+        let mut c = [0i32; 4];
+        if index < 8 {
+            c[index / 2] = if index.is_multiple_of(2) { 2 } else { -2 };
+        } else if index < Self::UNIT_COUNT {
+            let signs = index - 8;
+            for (k, x) in c.iter_mut().enumerate() {
+                *x = if signs & (1 << k) == 0 { 1 } else { -1 };
+            }
+        } else {
+            return None;
+        }
+        Some(HInt::from_raw(c[0], c[1], c[2], c[3]))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn units() -> Vec<Self> {
+        (0..Self::UNIT_COUNT).filter_map(Self::unit).collect()
+    }
+
     pub fn is_zero(self) -> bool {
         self.a == 0 && self.b == 0 && self.c == 0 && self.d == 0
     }
@@ -100,6 +185,13 @@ impl HInt {
         }
     }
 
+    // conj() in place: negates the i, j, k components
+    pub fn conj_mut(&mut self) {
+        self.b = -self.b;
+        self.c = -self.c;
+        self.d = -self.d;
+    }
+
     pub fn norm_squared(self) -> u64 {
         // N(q) = (a^2 + b^2 + c^2 + d^2) / 4 since stored as 2*value
         let a2: i64 = self.a as i64 * self.a as i64;
@@ -109,22 +201,101 @@ impl HInt {
         ((a2 + b2 + c2 + d2) / 4) as u64
     }
 
+    // Lossy floating companion to norm_squared: the Euclidean length |x|
+    #[cfg(feature = "std")]
+    pub fn magnitude(self) -> f64 {
+        (self.norm_squared() as f64).sqrt()
+    }
+
+    // N(a * b) == N(a) * N(b), where N(x) = x * conj(x). Every composition
+    // algebra satisfies this, so a false result means a sign error in the
+    // multiplication table. Compared in u128 so large inputs cannot wrap.
+    pub fn check_norm_multiplicative(a: Self, b: Self) -> bool {
+        (a * b).norm_squared() as u128 == a.norm_squared() as u128 * b.norm_squared() as u128
+    }
+
+    // Polarization of the norm form: N(a + b) - N(a) - N(b) = 2 Re(a conj(b)),
+    // twice the lattice_dot fraction and always an integer, even where that
+    // dot is a half. In raw terms Σ stored products / 2, without forming a + b.
+    pub fn bilinear_form(a: Self, b: Self) -> i64 {
+        let sum = a.a as i64 * b.a as i64 + a.b as i64 * b.b as i64
+            + a.c as i64 * b.c as i64 + a.d as i64 * b.d as i64;
+        sum / 2
+    }
+
+    pub fn real_part(self) -> f64 {
+        self.a as f64 / 2.0
+    }
+
+    // Re(x) as a reduced (numerator, denominator) pair, with denominator 2
+    // for half-integer values
+    pub fn scalar(self) -> (i64, u64) {
+        halve(self.a)
+    }
+
+    // Reduced trace x + conj(x) = 2·Re(x), which is the stored scalar
+    pub fn trace(self) -> i64 {
+        self.a as i64
+    }
+
+    // Norm of the pure imaginary part: N(x) = Re(x)² + imaginary_norm_squared,
+    // as a reduced (numerator, denominator) pair (a multiple of 1/4 in general)
+    pub fn imaginary_norm_squared(self) -> (u64, u64) {
+        let sum: i64 = self.b as i64 * self.b as i64 + self.c as i64 * self.c as i64 + self.d as i64 * self.d as i64;
+        reduce_ratio(sum as u128, 4)
+    }
+
+    // Hurwitz division: the quotient may come from either the integer (Lipschitz)
+    // lattice or the half-integer coset, whichever leaves the smaller remainder.
+    // Unlike div_rem_lipschitz this always gives N(r) < N(d): every point of
+    // R⁴ lies within squared distance 1/2 of a Hurwitz point, so in fact
+    // N(r) <= N(d) / 2. The division is on the right, self = q * d + r; see
+    // div_rem_left for d * q + r.
+    #[cfg(feature = "std")]
     pub fn div_rem(self, d: HInt) -> Result<(HInt, HInt), HIntError> {
+        let (q_int, r_int) = self.div_rem_lipschitz(d)?;
+
+        let d_norm = d.norm_squared() as i64;
+        let [a, b, c, e] = self.mul_wide(d.conj());
+
+        // Nearest point of the half-integer coset: floor(x) + 1/2, stored as *2
+        let half = |x: i64| ((x as f64) / (d_norm as f64 * 4.0)).floor() as i32 * 2 + 1;
+        let q_half = HInt { a: half(a), b: half(b), c: half(c), d: half(e) };
+        let r_half = self - (q_half * d);
+
+        let (q, r) = if r_half.norm_squared() < r_int.norm_squared() {
+            (q_half, r_half)
+        } else {
+            (q_int, r_int)
+        };
+        debug_assert!(self.div_rem_holds(d, q, r), "HInt div_rem: self != q * d + r");
+        Ok((q, r))
+    }
+
+    // self == q * d + r on the stored values, with the product kept wide
+    #[cfg(feature = "std")]
+    fn div_rem_holds(self, d: Self, q: Self, r: Self) -> bool {
+        let (wide, r, s) = (q.mul_wide(d), r.raw_components(), self.raw_components());
+        (0..4).all(|k| wide[k] / 2 + r[k] as i64 == s[k] as i64)
+    }
+
+    // Lipschitz division: rounds the quotient to integer coordinates only (Z⁴).
+    // The Lipschitz ring is not Euclidean, so N(r) can equal N(d).
+    #[cfg(feature = "std")]
+    pub fn div_rem_lipschitz(self, d: HInt) -> Result<(HInt, HInt), HIntError> {
         if d.is_zero() {
             return Err(HIntError::DivisionByZero);
         }
 
         let d_norm = d.norm_squared() as i64;
-        let d_conj = d.conj();
+        // self * conj(d) in i64 (stored *4), as it may exceed the i32 range
+        let num = self.mul_wide(d.conj());
 
-        // Compute self * conj(d) (result is already stored * 2)
-        let num_prod = self * d_conj;
-
-        // Divide by norm and round (need to divide by 2 more for storage)
-        let q_a_f = (num_prod.a as f64) / (d_norm as f64 * 2.0);
-        let q_b_f = (num_prod.b as f64) / (d_norm as f64 * 2.0);
-        let q_c_f = (num_prod.c as f64) / (d_norm as f64 * 2.0);
-        let q_d_f = (num_prod.d as f64) / (d_norm as f64 * 2.0);
+        // Divide by norm and round
+        let q_a_f = (num[0] as f64) / (d_norm as f64 * 4.0);
+        let q_b_f = (num[1] as f64) / (d_norm as f64 * 4.0);
+        let q_c_f = (num[2] as f64) / (d_norm as f64 * 4.0);
+        let q_d_f = (num[3] as f64) / (d_norm as f64 * 4.0);
 
         // Round and store as *2
         let q = HInt {
@@ -139,6 +310,60 @@ impl HInt {
         Ok((q, r))
     }
 
+    // Hurwitz division without floating point. The numerator self * conj(d) is
+    // kept in i64, so this also works where that product overflows i32.
+    pub fn div_rem_int(self, d: HInt) -> Result<(HInt, HInt), HIntError> {
+        if d.is_zero() {
+            return Err(HIntError::DivisionByZero);
+        }
+
+        // Stored values are *2, so the wide product is 4 * self * conj(d)
+        let den = 4 * d.norm_squared() as i64;
+        let num = self.mul_wide(d.conj());
+
+        let round = |x: i64| num_utils::round_div(x, den) as i32 * 2;
+        let half = |x: i64| num_utils::floor_div(x, den) as i32 * 2 + 1;
+
+        let q_int = HInt { a: round(num[0]), b: round(num[1]), c: round(num[2]), d: round(num[3]) };
+        let q_half = HInt { a: half(num[0]), b: half(num[1]), c: half(num[2]), d: half(num[3]) };
+        let r_int = self - (q_int * d);
+        let r_half = self - (q_half * d);
+
+        if r_half.norm_squared() < r_int.norm_squared() {
+            Ok((q_half, r_half))
+        } else {
+            Ok((q_int, r_int))
+        }
+    }
+
+    // Product of the stored (*2) components before the final /2
+    fn mul_wide(self, other: HInt) -> [i64; 4] {
+        // Quaternion multiplication: (a+bi+cj+dk)(e+fi+gj+hk)
+        // i²=j²=k²=ijk=-1, ij=k, jk=i, ki=j, ji=-k, kj=-i, ik=-j
+        let a = self.a as i64 * other.a as i64
+            - self.b as i64 * other.b as i64
+            - self.c as i64 * other.c as i64
+            - self.d as i64 * other.d as i64;
+
+        let b = self.a as i64 * other.b as i64
+            + self.b as i64 * other.a as i64
+            + self.c as i64 * other.d as i64
+            - self.d as i64 * other.c as i64;
+
+        let c = self.a as i64 * other.c as i64
+            - self.b as i64 * other.d as i64
+            + self.c as i64 * other.a as i64
+            + self.d as i64 * other.b as i64;
+
+        let d = self.a as i64 * other.d as i64
+            + self.b as i64 * other.c as i64
+            - self.c as i64 * other.b as i64
+            + self.d as i64 * other.a as i64;
+
+        [a, b, c, d]
+    }
+
+    #[cfg(feature = "std")]
     pub fn div_exact(self, d: HInt) -> Result<HInt, HIntError> {
         let (q, r) = self.div_rem(d)?;
         if r.is_zero() {
@@ -148,33 +373,87 @@ impl HInt {
         }
     }
 
+    // div_rem divides on the right: self = q * d + r, with q near self * d⁻¹.
+    // This is the same as div_rem, under a name that says so.
+    #[cfg(feature = "std")]
+    pub fn div_rem_right(self, d: HInt) -> Result<(HInt, HInt), HIntError> {
+        self.div_rem(d)
+    }
+
+    // Left division: self = d * q + r, with q near d⁻¹ * self. Conjugation
+    // reverses products, so this is div_rem on conj(self) and conj(d) with
+    // the results conjugated back; N(r) is bounded exactly as for div_rem.
+    #[cfg(feature = "std")]
+    pub fn div_rem_left(self, d: HInt) -> Result<(HInt, HInt), HIntError> {
+        let (q, r) = self.conj().div_rem(d.conj())?;
+        Ok((q.conj(), r.conj()))
+    }
+
+    // q with self = q * d exactly
+    #[cfg(feature = "std")]
+    pub fn div_exact_right(self, d: HInt) -> Result<HInt, HIntError> {
+        self.div_exact(d)
+    }
+
+    // q with self = d * q exactly
+    #[cfg(feature = "std")]
+    pub fn div_exact_left(self, d: HInt) -> Result<HInt, HIntError> {
+        let (q, r) = self.div_rem_left(d)?;
+        if r.is_zero() {
+            Ok(q)
+        } else {
+            Err(HIntError::NotDivisible)
+        }
+    }
+
+    // Right division self * den⁻¹ = self * conj(den) / N(den). The left
+    // quotient den⁻¹ * self is den.inv_fraction()? * HIFraction::from(self).
     pub fn div_to_fraction(self, den: HInt) -> Result<HIFraction, HIntError> {
         if den.is_zero() {
             return Err(HIntError::DivisionByZero);
         }
         Ok(HIFraction {
-            num: self,
+            num: self * den.conj(),
             den: den.norm_squared(),
         })
     }
 
+    // Lowest terms, unique per value as for CInt::reduce_fraction; the only
+    // extra rule is that num stays a Hurwitz quaternion
     pub fn reduce_fraction(frac: HIFraction) -> HIFraction {
-        let a_abs = frac.num.a.abs() as u64;
-        let b_abs = frac.num.b.abs() as u64;
-        let c_abs = frac.num.c.abs() as u64;
-        let d_abs = frac.num.d.abs() as u64;
+        let a_abs = frac.num.a.unsigned_abs() as u64;
+        let b_abs = frac.num.b.unsigned_abs() as u64;
+        let c_abs = frac.num.c.unsigned_abs() as u64;
+        let d_abs = frac.num.d.unsigned_abs() as u64;
         
         let g1 = num_utils::integer_gcd(a_abs, b_abs);
         let g2 = num_utils::integer_gcd(c_abs, d_abs);
         let g3 = num_utils::integer_gcd(g1, g2);
-        let g = num_utils::integer_gcd(g3, frac.den);
+        let mut g = num_utils::integer_gcd(g3, frac.den);
         
         if g <= 1 {
             return frac;
         }
 
+        let divide = |g: u64| HInt::from_halves(
+            (frac.num.a as i64 / g as i64) as i32,
+            (frac.num.b as i64 / g as i64) as i32,
+            (frac.num.c as i64 / g as i64) as i32,
+            (frac.num.d as i64 / g as i64) as i32,
+        );
+
+        // The stored components are *2, so dividing by an even g can leave mixed
+        // parity (not a Hurwitz quaternion); back off to g/2, which keeps all even
+        let num = match divide(g) {
+            Ok(num) => num,
+            Err(_) => {
+                g /= 2;
+                divide(g).unwrap_or(frac.num)
+            }
+        };
+
         HIFraction {
-            num: frac.num,
+            num,
             den: frac.den / g,
         }
     }
@@ -189,6 +468,34 @@ impl HInt {
         })
     }
 
+    // self^exp by repeated squaring; powers of one element commute, so the
+    // grouping does not matter
+    pub fn pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut acc = HInt::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc * base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base * base;
+            }
+        }
+        acc
+    }
+
+    // self^exp in the field of fractions, reduced: a negative exponent raises
+    // inv_fraction() to |exp|, and exp == 0 gives 1/1
+    pub fn pow_frac(self, exp: i32) -> Result<HIFraction, HIntError> {
+        let base = if exp < 0 { self.inv_fraction()? } else { HIFraction::from(self) };
+        let mut acc = HIFraction::from(HInt::one());
+        for _ in 0..exp.unsigned_abs() {
+            acc = acc * base;
+        }
+        Ok(HInt::reduce_fraction(acc))
+    }
+
     pub fn inv_unit(self) -> Result<HInt, HIntError> {
         if !self.is_unit() {
             return Err(HIntError::NoInverse);
@@ -196,13 +503,50 @@ impl HInt {
         Ok(self.conj())
     }
 
+    #[cfg(feature = "std")]
     pub fn gcd(mut a: HInt, mut b: HInt) -> HInt {
         while !b.is_zero() {
             let (_, r) = a.div_rem(b).unwrap_or((HInt::zero(), a));
             a = b;
             b = r;
         }
-        a.normalize()
+        a.canonical_associate()
+    }
+
+    // gcd folded left to right over the slice, with the conventions of
+    // CInt::gcd_many. As for gcd itself, the result is one gcd of the class;
+    // reordering the slice can give a different unit multiple of it.
+    #[cfg(feature = "std")]
+    pub fn gcd_many(xs: &[Self]) -> Self {
+        let mut acc = HInt::zero();
+        for &x in xs {
+            acc = HInt::gcd(acc, x);
+            if acc.is_unit() {
+                break;
+            }
+        }
+        acc
+    }
+
+    // Euclidean gcd restricted to Lipschitz quotients. Because the Lipschitz
+    // quaternions are not a PID (the Hurwitz order is), the remainder can stop
+    // shrinking; returns None when that happens instead of looping forever.
+    #[cfg(feature = "std")]
+    pub fn gcd_lipschitz(mut a: HInt, mut b: HInt) -> Option<HInt> {
+        while !b.is_zero() {
+            let (_, r) = a.div_rem_lipschitz(b).ok()?;
+            if r.norm_squared() >= b.norm_squared() {
+                return None;
+            }
+            a = b;
+            b = r;
+        }
+        Some(a.normalize())
+    }
+
+    // True when every component is an integer (the Lipschitz sublattice Z⁴)
+    pub fn is_lipschitz(self) -> bool {
+        self.a % 2 == 0 && self.b % 2 == 0 && self.c % 2 == 0 && self.d % 2 == 0
     }
 
     pub fn normalize(self) -> HInt {
@@ -225,26 +569,77 @@ impl HInt {
         self
     }
 
-    pub fn associates(self) -> [HInt; 8] {
-        let one = HInt::one();
-        let neg_one = -one;
-        let i = HInt::i();
-        let neg_i = -i;
-        let j = HInt::j();
-        let neg_j = -j;
-        let k = HInt::k();
-        let neg_k = -k;
-
-        [
-            self * one,
-            self * neg_one,
-            self * i,
-            self * neg_i,
-            self * j,
-            self * neg_j,
-            self * k,
-            self * neg_k,
-        ]
+    // The unique representative of the class {u * self} over all 24 units
+    // (left multiples, as in associates): the one whose stored components are
+    // lexicographically largest. Zero maps to itself and every unit to 1.
+    pub fn canonical_associate(self) -> Self {
+        (0..Self::UNIT_COUNT)
+            .filter_map(Self::unit)
+            .map(|u| u * self)
+            .max_by_key(|x| x.raw_components())
+            .unwrap()
+    }
+
+    // True when other = u * self for one of the 24 units
+    pub fn eq_up_to_unit(self, other: Self) -> bool {
+        self.norm_squared() == other.norm_squared()
+            && self.canonical_associate() == other.canonical_associate()
+    }
+
+    // unit * self, a norm-preserving symmetry of the D4 lattice. Multiplying
+    // on the right (self * unit) is also one, but a different map unless unit
+    // is ±1, since the two sides differ by conjugation x ↦ u x u⁻¹.
+    pub fn rotate(self, unit: Self) -> Result<Self, HIntError> {
+        if !unit.is_unit() {
+            return Err(HIntError::NotAUnit);
+        }
+        Ok(unit * self)
+    }
+
+    // rotate() applied to every point
+    #[cfg(feature = "std")]
+    pub fn apply_symmetry(points: &[Self], unit: Self) -> Result<Vec<Self>, HIntError> {
+        if !unit.is_unit() {
+            return Err(HIntError::NotAUnit);
+        }
+        Ok(points.iter().map(|&p| unit * p).collect())
+    }
+
+    // Every distinct unit * self over the 24 Hurwitz units: all 24 for
+    // nonzero self, since there are no zero divisors
+    #[cfg(feature = "std")]
+    pub fn associates(self) -> Vec<HInt> {
+        let mut result: Vec<HInt> = Vec::with_capacity(Self::UNIT_COUNT);
+        for u in Self::units() {
+            let x = u * self;
+            if !result.contains(&x) {
+                result.push(x);
+            }
+        }
+        result
+    }
+
+    // Parse comma-separated actual values, e.g. "1,1,0,0" or "0.5,0.5,0.5,0.5"
+    #[cfg(feature = "std")]
+    pub fn from_coords_str(s: &str) -> Result<Self, HIntError> {
+        let v = s.split(',')
+            .map(halves::parse)
+            .collect::<Option<Vec<i32>>>()
+            .ok_or(HIntError::InvalidFormat)?;
+        if v.len() != 4 {
+            return Err(HIntError::InvalidFormat);
+        }
+        Self::from_halves(v[0], v[1], v[2], v[3])
+    }
+
+    // Comma-separated actual values, readable by from_coords_str
+    #[cfg(feature = "std")]
+    pub fn to_coords_str(self) -> String {
+        [self.a, self.b, self.c, self.d]
+            .iter()
+            .map(|&x| halves::format(x))
+            .collect::<Vec<_>>()
+            .join(",")
     }
 
     pub fn to_float_components(self) -> (f64, f64, f64, f64) {
@@ -256,6 +651,19 @@ impl HInt {
         )
     }
 
+    // Every component of to_float_components within eps of the target
+    pub fn approx_eq_float_components(self, target: (f64, f64, f64, f64), eps: f64) -> bool {
+        let (a, b, c, d) = self.to_float_components();
+        [(a, target.0), (b, target.1), (c, target.2), (d, target.3)]
+            .iter()
+            .all(|&(x, y)| x - y <= eps && y - x <= eps)
+    }
+
+    // Commutator [a,b] = a*b - b*a
+    pub fn commutator(a: HInt, b: HInt) -> HInt {
+        a * b - b * a
+    }
+
     pub fn is_anticommutative_pair(a: HInt, b: HInt) -> bool {
         a * b == -(b * a)
     }
@@ -265,6 +673,195 @@ impl HInt {
     }
 }
 
+impl Default for HInt {
+    fn default() -> Self {
+        HInt::zero()
+    }
+}
+
+impl PartialEq for HIFraction {
+    fn eq(&self, other: &Self) -> bool {
+        let (x, y) = (self.normalized(), other.normalized());
+        x.num == y.num && x.den == y.den
+    }
+}
+
+impl core::hash::Hash for HIFraction {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        let x = self.normalized();
+        x.num.hash(state);
+        x.den.hash(state);
+    }
+}
+
+impl HIFraction {
+    // Canonical lowest-terms form
+    pub fn normalized(self) -> Self {
+        HInt::reduce_fraction(self)
+    }
+
+    // N(num) / den² as a reduced (numerator, denominator) pair; exact, with
+    // the intermediate den² held in u128
+    pub fn norm_squared(self) -> (u64, u64) {
+        reduce_ratio(self.num.norm_squared() as u128, self.den as u128 * self.den as u128)
+    }
+}
+
+impl Default for HIFraction {
+    fn default() -> Self {
+        HIFraction {
+            num: HInt::zero(),
+            den: 1,
+        }
+    }
+}
+
+impl From<HInt> for HIFraction {
+    fn from(x: HInt) -> Self {
+        HIFraction { num: x, den: 1 }
+    }
+}
+
+impl HIFraction {
+    // Rewrite over the common denominator lcm(self.den, other.den)
+    fn over_common_den(self, other: Self) -> (HInt, HInt, u64) {
+        let g = num_utils::integer_gcd(self.den, other.den);
+        let lcm = self.den / g * other.den;
+        // Scaling the stored components directly: HInt::new(k, ..) would
+        // itself overflow once 2k passes i32::MAX
+        let scale = |num: HInt, k: u64| {
+            i32::try_from(k).ok()
+                .and_then(|k| num.checked_scale(k))
+                .expect("HIFraction denominator overflow")
+        };
+        (scale(self.num, lcm / self.den), scale(other.num, lcm / other.den), lcm)
+    }
+}
+
+impl Add for HIFraction {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let (a, b, den) = self.over_common_den(rhs);
+        HInt::reduce_fraction(HIFraction { num: a + b, den })
+    }
+}
+
+impl Sub for HIFraction {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        let (a, b, den) = self.over_common_den(rhs);
+        HInt::reduce_fraction(HIFraction { num: a - b, den })
+    }
+}
+
+// The denominators are rational integers, which commute with everything, so
+// (p/m)(q/n) = pq/(mn) with the numerators multiplied in the order written
+impl Mul for HIFraction {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let den = self.den.checked_mul(rhs.den).expect("HIFraction denominator overflow");
+        HInt::reduce_fraction(HIFraction { num: self.num * rhs.num, den })
+    }
+}
+
+// Z[i] embeds as the a + bi slots
+impl From<CInt> for HInt {
+    fn from(z: CInt) -> Self {
+        HInt::new(z.a, z.b, 0, 0)
+    }
+}
+
+// Projects back to Z[i]; fails unless j = k = 0 and a, b are integers
+impl TryFrom<HInt> for CInt {
+    type Error = HIntError;
+
+    fn try_from(q: HInt) -> Result<Self, HIntError> {
+        if q.c != 0 || q.d != 0 || q.a % 2 != 0 || q.b % 2 != 0 {
+            return Err(HIntError::NotInSubalgebra);
+        }
+        Ok(CInt::new(q.a / 2, q.b / 2))
+    }
+}
+
+// Integer coordinates, through new (so stored doubled). Half-integer values
+// need from_halves instead.
+impl From<(i32, i32, i32, i32)> for HInt {
+    fn from((a, b, c, d): (i32, i32, i32, i32)) -> Self {
+        HInt::new(a, b, c, d)
+    }
+}
+
+impl From<[i32; 4]> for HInt {
+    fn from([a, b, c, d]: [i32; 4]) -> Self {
+        HInt::new(a, b, c, d)
+    }
+}
+
+impl HInt {
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.zip_raw(rhs, i32::checked_add)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.zip_raw(rhs, i32::checked_sub)
+    }
+
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        self.zip_raw(rhs, |x, y| Some(x.wrapping_add(y))).unwrap()
+    }
+
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.zip_raw(rhs, |x, y| Some(x.wrapping_sub(y))).unwrap()
+    }
+
+    // n * self by scaling each component, much cheaper than multiplying by n
+    // as a ring element; overflows like Add
+    pub fn scale(self, n: i32) -> Self {
+        self.map_raw(|x| Some(x * n)).unwrap()
+    }
+
+    pub fn checked_scale(self, n: i32) -> Option<Self> {
+        self.map_raw(|x| x.checked_mul(n))
+    }
+
+    // Mul panics on overflow, like CInt; this reports it instead
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, HIntError> {
+        // Beyond this bound the i64 products themselves could overflow, and
+        // since N(xy) = N(x)N(y) the result would be far outside i32 anyway
+        if max_abs(&self.raw_components()) * max_abs(&rhs.raw_components()) >= 1 << 61 {
+            return Err(HIntError::Overflow);
+        }
+        HInt::from_doubled_product(self.mul_wide(rhs)).ok_or(HIntError::Overflow)
+    }
+
+    // Products of *2-stored values are *4; divide by 2 to keep *2 storage
+    fn from_doubled_product(wide: [i64; 4]) -> Option<Self> {
+        let mut c = [0i32; 4];
+        for k in 0..4 {
+            c[k] = i32::try_from(wide[k] / 2).ok()?;
+        }
+        Some(HInt::from_raw(c[0], c[1], c[2], c[3]))
+    }
+
+    fn zip_raw(self, rhs: Self, op: impl Fn(i32, i32) -> Option<i32>) -> Option<Self> {
+        let (x, y) = (self.raw_components(), rhs.raw_components());
+        let mut c = [0i32; 4];
+        for k in 0..4 {
+            c[k] = op(x[k], y[k])?;
+        }
+        Some(HInt::from_raw(c[0], c[1], c[2], c[3]))
+    }
+
+    fn map_raw(self, op: impl Fn(i32) -> Option<i32>) -> Option<Self> {
+        let x = self.raw_components();
+        let mut c = [0i32; 4];
+        for k in 0..4 {
+            c[k] = op(x[k])?;
+        }
+        Some(HInt::from_raw(c[0], c[1], c[2], c[3]))
+    }
+}
+
 impl Add for HInt {
     type Output = HInt;
     fn add(self, other: HInt) -> HInt {
@@ -292,38 +889,17 @@ impl Sub for HInt {
 impl Mul for HInt {
     type Output = HInt;
     fn mul(self, other: HInt) -> HInt {
-        // Quaternion multiplication: (a+bi+cj+dk)(e+fi+gj+hk)
-        // i²=j²=k²=ijk=-1, ij=k, jk=i, ki=j, ji=-k, kj=-i, ik=-j
-        
-        // Working with 2*values, result needs /4 total (but we keep *2 storage)
-        // So multiply and divide by 2 once
-        let a = self.a as i64 * other.a as i64
-            - self.b as i64 * other.b as i64
-            - self.c as i64 * other.c as i64
-            - self.d as i64 * other.d as i64;
-
-        let b = self.a as i64 * other.b as i64
-            + self.b as i64 * other.a as i64
-            + self.c as i64 * other.d as i64
-            - self.d as i64 * other.c as i64;
-
-        let c = self.a as i64 * other.c as i64
-            - self.b as i64 * other.d as i64
-            + self.c as i64 * other.a as i64
-            + self.d as i64 * other.b as i64;
-
-        let d = self.a as i64 * other.d as i64
-            + self.b as i64 * other.c as i64
-            - self.c as i64 * other.b as i64
-            + self.d as i64 * other.a as i64;
+        HInt::from_doubled_product(self.mul_wide(other)).expect("HInt multiplication overflow")
+    }
+}
 
-        // Divide by 2 to maintain *2 storage (since we multiplied *2 * *2 = *4)
-        HInt {
-            a: (a / 2) as i32,
-            b: (b / 2) as i32,
-            c: (c / 2) as i32,
-            d: (d / 2) as i32,
-        }
+// a / b is the right quotient a * b⁻¹; for the left quotient b⁻¹ * a use
+// b.inv_fraction() and multiply fractions in that order
+impl Div for HInt {
+    type Output = HIFraction;
+    // Panics on a zero divisor; div_to_fraction is the checked form
+    fn div(self, rhs: Self) -> HIFraction {
+        self.div_to_fraction(rhs).expect("HInt division by zero")
     }
 }
 
@@ -339,3 +915,29 @@ impl Neg for HInt {
     }
 }
 
+// Stored (*2) component by position, 0 being the scalar part; indices past 3 panic
+impl Index<usize> for HInt {
+    type Output = i32;
+    fn index(&self, index: usize) -> &i32 {
+        match index {
+            0 => &self.a,
+            1 => &self.b,
+            2 => &self.c,
+            3 => &self.d,
+            _ => panic!("HInt component index out of range: {}", index),
+        }
+    }
+}
+
+impl IndexMut<usize> for HInt {
+    fn index_mut(&mut self, index: usize) -> &mut i32 {
+        match index {
+            0 => &mut self.a,
+            1 => &mut self.b,
+            2 => &mut self.c,
+            3 => &mut self.d,
+            _ => panic!("HInt component index out of range: {}", index),
+        }
+    }
+}
+
@@ -1,8 +1,66 @@
 pub mod cint;
+pub mod eint;
 pub mod hint;
 pub mod oint;
 pub mod display;
+#[cfg(feature = "std")]
+mod halves;
+#[cfg(feature = "rand")]
+pub mod random;
 
+// Add, Sub and Neg on all four types behave like the i32 operators on the
+// stored components: overflow panics in debug builds and wraps in release.
+// HInt and OInt store doubled components, so for them that happens above
+// i32::MAX / 2. checked_add/sub and wrapping_add/sub pick one explicitly.
 pub use cint::CInt;
+pub use eint::EInt;
 pub use hint::HInt;
 pub use oint::OInt;
+
+// Exactly N items of iter as an array, or None for any other count
+pub(crate) fn collect_array<const N: usize, I: IntoIterator<Item = i32>>(iter: I) -> Option<[i32; N]> {
+    let mut it = iter.into_iter();
+    let mut out = [0i32; N];
+    for slot in out.iter_mut() {
+        *slot = it.next()?;
+    }
+    if it.next().is_some() {
+        return None;
+    }
+    Some(out)
+}
+
+// Little-endian i32 encoding shared by the to_le_bytes / from_le_bytes pairs;
+// out has exactly four bytes per value
+pub(crate) fn write_le(values: &[i32], out: &mut [u8]) {
+    for (chunk, x) in out.chunks_exact_mut(4).zip(values) {
+        chunk.copy_from_slice(&x.to_le_bytes());
+    }
+}
+
+pub(crate) fn read_le(bytes: &[u8], out: &mut [i32]) {
+    for (x, chunk) in out.iter_mut().zip(bytes.chunks_exact(4)) {
+        *x = i32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+}
+
+// Largest |x| over values, for bounding products before computing them
+pub(crate) fn max_abs(values: &[i32]) -> u64 {
+    values.iter().map(|x| x.unsigned_abs() as u64).max().unwrap_or(0)
+}
+
+// A stored (*2) value as a reduced (numerator, denominator) pair
+pub(crate) fn halve(raw: i32) -> (i64, u64) {
+    if raw % 2 == 0 { (raw as i64 / 2, 1) } else { (raw as i64, 2) }
+}
+
+// num / den in lowest terms, narrowed back to u64 (panics if that overflows)
+pub(crate) fn reduce_ratio(num: u128, den: u128) -> (u64, u64) {
+    let (mut x, mut y) = (num, den);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    let g = x.max(1);
+    let narrow = |v: u128| u64::try_from(v / g).expect("fraction norm overflow");
+    (narrow(num), narrow(den))
+}
@@ -1,4 +1,9 @@
-use std::ops::{Add, Sub, Mul, Neg};
+use core::ops::{Add, Sub, Mul, Div, Neg, Index, IndexMut};
+use crate::types::cint::CInt;
+use crate::types::{collect_array, halve, max_abs, read_le, reduce_ratio, write_le};
+#[cfg(feature = "std")]
+use crate::types::halves;
+use crate::types::hint::HInt;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OIntError {
@@ -6,15 +11,31 @@ pub enum OIntError {
     DivisionByZero,
     NotDivisible,
     NoInverse,
+    NotAUnit,
     InvalidHalfInteger,
+    InvalidFormat,
+    NotInSubalgebra,
+    NotInLattice,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+// Multiplication kernel for OInt::mul_with; both give identical results, and
+// the `*` operator uses Table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MulStrategy {
+    Table,
+    CayleyDickson,
+}
+
+// `==` and `Hash` compare the reduced form from `normalized()`, so value-equal
+// fractions such as 2/4 and 1/2 are equal and hash equally
+#[derive(Clone, Copy, Eq)]
 pub struct OIFraction {
     pub num: OInt,
     pub den: u64,
 }
 
+// `==` and `Hash` agree with equality of actual values, since the stored
+// doubling is one-to-one
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(C)]
 pub struct OInt {
@@ -36,55 +57,80 @@ mod fano_plane {
         if j == 0 { return (1, i); }
         if i == j { return (-1, 0); }  // e_i * e_i = -1
 
-        // Fano plane multiplication rules (Cayley-Dickson construction)
+        // Fano plane multiplication rules (Cayley-Dickson doubling of i, j, k by e4)
+        // Oriented lines: (1,2,3) (1,4,5) (1,7,6) (2,4,6) (2,5,7) (3,4,7) (3,6,5)
         match (i, j) {
-            (1, 2) => (1, 4),   // e1*e2 = e4
-            (2, 1) => (-1, 4),  // e2*e1 = -e4
-            (2, 3) => (1, 5),   // e2*e3 = e5
-            (3, 2) => (-1, 5),
-            (3, 1) => (1, 6),   // e3*e1 = e6
-            (1, 3) => (-1, 6),
-            (1, 4) => (-1, 2),  // e1*e4 = -e2
-            (4, 1) => (1, 2),
-            (4, 2) => (1, 1),   // e4*e2 = e1
-            (2, 4) => (-1, 1),
-            (1, 5) => (1, 3),   // e1*e5 = e3
-            (5, 1) => (-1, 3),
-            (5, 3) => (1, 1),   // e5*e3 = e1
-            (3, 5) => (-1, 1),
-            (1, 6) => (-1, 5),  // e1*e6 = -e5
-            (6, 1) => (1, 5),
-            (6, 5) => (1, 1),   // e6*e5 = e1
-            (5, 6) => (-1, 1),
+            (1, 2) => (1, 3),   // e1*e2 = e3
+            (2, 1) => (-1, 3),
+            (2, 3) => (1, 1),   // e2*e3 = e1
+            (3, 2) => (-1, 1),
+            (3, 1) => (1, 2),   // e3*e1 = e2
+            (1, 3) => (-1, 2),
+            (1, 4) => (1, 5),   // e1*e4 = e5
+            (4, 1) => (-1, 5),
+            (4, 5) => (1, 1),   // e4*e5 = e1
+            (5, 4) => (-1, 1),
+            (5, 1) => (1, 4),   // e5*e1 = e4
+            (1, 5) => (-1, 4),
             (1, 7) => (1, 6),   // e1*e7 = e6
             (7, 1) => (-1, 6),
             (7, 6) => (1, 1),   // e7*e6 = e1
             (6, 7) => (-1, 1),
-            (2, 5) => (-1, 7),  // e2*e5 = -e7
-            (5, 2) => (1, 7),
-            (2, 6) => (1, 7),   // e2*e6 = e7
-            (6, 2) => (-1, 7),
-            (3, 4) => (1, 7),   // e3*e4 = e7
-            (4, 3) => (-1, 7),
-            (3, 7) => (-1, 4),  // e3*e7 = -e4
-            (7, 3) => (1, 4),
-            (4, 5) => (1, 6),   // e4*e5 = e6
-            (5, 4) => (-1, 6),
-            (4, 6) => (-1, 5),  // e4*e6 = -e5
-            (6, 4) => (1, 5),
-            (4, 7) => (1, 2),   // e4*e7 = e2
-            (7, 4) => (-1, 2),
-            (5, 7) => (-1, 4),  // e5*e7 = -e4
-            (7, 5) => (1, 4),
-            (6, 3) => (1, 7),   // e6*e3 = e7
-            (3, 6) => (-1, 7),
+            (6, 1) => (1, 7),   // e6*e1 = e7
+            (1, 6) => (-1, 7),
+            (2, 4) => (1, 6),   // e2*e4 = e6
+            (4, 2) => (-1, 6),
+            (4, 6) => (1, 2),   // e4*e6 = e2
+            (6, 4) => (-1, 2),
+            (6, 2) => (1, 4),   // e6*e2 = e4
+            (2, 6) => (-1, 4),
+            (2, 5) => (1, 7),   // e2*e5 = e7
+            (5, 2) => (-1, 7),
+            (5, 7) => (1, 2),   // e5*e7 = e2
+            (7, 5) => (-1, 2),
             (7, 2) => (1, 5),   // e7*e2 = e5
             (2, 7) => (-1, 5),
-            _ => (1, 0),  // Shouldn't reach here
+            (3, 4) => (1, 7),   // e3*e4 = e7
+            (4, 3) => (-1, 7),
+            (4, 7) => (1, 3),   // e4*e7 = e3
+            (7, 4) => (-1, 3),
+            (7, 3) => (1, 4),   // e7*e3 = e4
+            (3, 7) => (-1, 4),
+            (3, 6) => (1, 5),   // e3*e6 = e5
+            (6, 3) => (-1, 5),
+            (6, 5) => (1, 3),   // e6*e5 = e3
+            (5, 6) => (-1, 3),
+            (5, 3) => (1, 6),   // e5*e3 = e6
+            (3, 5) => (-1, 6),
+            _ => unreachable!("basis indices must be < 8"),
         }
     }
 }
 
+// Quaternion helpers on raw i64 components for the Cayley-Dickson multiply
+mod cayley_dickson {
+    pub fn quat_mul(x: [i64; 4], y: [i64; 4]) -> [i64; 4] {
+        [
+            x[0] * y[0] - x[1] * y[1] - x[2] * y[2] - x[3] * y[3],
+            x[0] * y[1] + x[1] * y[0] + x[2] * y[3] - x[3] * y[2],
+            x[0] * y[2] - x[1] * y[3] + x[2] * y[0] + x[3] * y[1],
+            x[0] * y[3] + x[1] * y[2] - x[2] * y[1] + x[3] * y[0],
+        ]
+    }
+
+    pub fn quat_conj(x: [i64; 4]) -> [i64; 4] {
+        [x[0], -x[1], -x[2], -x[3]]
+    }
+}
+
+// Half-integer units of the integral octonions are (±e_a ± e_b ± e_c ± e_d)/2 over
+// these 14 quadruples (the maximal order containing the Hurwitz quaternions)
+const UNIT_QUADS: [[usize; 4]; 14] = [
+    [0, 1, 2, 3], [0, 1, 4, 5], [0, 1, 6, 7], [0, 2, 4, 7], [0, 2, 5, 6],
+    [0, 3, 4, 6], [0, 3, 5, 7], [1, 2, 4, 6], [1, 2, 5, 7], [1, 3, 4, 7],
+    [1, 3, 5, 6], [2, 3, 4, 5], [2, 3, 6, 7], [4, 5, 6, 7],
+];
+
 mod num_utils {
     pub fn integer_gcd(mut a: u64, mut b: u64) -> u64 {
         while b != 0 {
@@ -94,11 +140,19 @@ mod num_utils {
         }
         a
     }
+
+    // round(n / d) for d > 0, halves away from zero like f64::round
+    pub fn round_div(n: i64, d: i64) -> i64 {
+        let (n, d) = (n as i128, d as i128);
+        let q = (2 * n.abs() + d) / (2 * d);
+        (if n < 0 { -q } else { q }) as i64
+    }
 }
 
 impl OInt {
     // Create from integers (stored as 2*actual)
-    pub fn new(a: i32, b: i32, c: i32, d: i32, e: i32, f: i32, g: i32, h: i32) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(a: i32, b: i32, c: i32, d: i32, e: i32, f: i32, g: i32, h: i32) -> Self {
         OInt {
             a: a * 2,
             b: b * 2,
@@ -112,6 +166,7 @@ impl OInt {
     }
 
     // Create from half-integers (all same parity)
+    #[allow(clippy::too_many_arguments)]
     pub fn from_halves(a: i32, b: i32, c: i32, d: i32, e: i32, f: i32, g: i32, h: i32) 
         -> Result<Self, OIntError> {
         let components = [a, b, c, d, e, f, g, h];
@@ -125,21 +180,136 @@ impl OInt {
         Ok(OInt { a, b, c, d, e, f, g, h })
     }
 
-    pub fn zero() -> Self {
+    // Build an E₈ point from halves (the stored *2 values). Beyond the
+    // same-parity check of from_halves, the coordinate sum must be even,
+    // i.e. the halves must sum to a multiple of 4 (see is_in_lattice). This
+    // is the lattice model of e8_coset and is_lattice_member, so one() and
+    // the mixed-parity units are rejected.
+    pub fn from_e8(coords: [i32; 8]) -> Result<Self, OIntError> {
+        let [a, b, c, d, e, f, g, h] = coords;
+        let x = OInt::from_halves(a, b, c, d, e, f, g, h)?;
+        if !OInt::is_in_lattice(x.to_lattice_vector()) {
+            return Err(OIntError::NotInLattice);
+        }
+        Ok(x)
+    }
+
+    // from_halves plus the even-sum rule of is_in_lattice (stored sum ≡ 0 mod
+    // 4), so only E₈ points with one parity are accepted. Both failures are
+    // InvalidHalfInteger. Mixed-parity ring elements such as one() are not
+    // points of this model and are rejected like they are by from_halves.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_halves_strict(a: i32, b: i32, c: i32, d: i32, e: i32, f: i32, g: i32, h: i32)
+        -> Result<Self, OIntError> {
+        let x = OInt::from_halves(a, b, c, d, e, f, g, h)?;
+        if !OInt::is_in_lattice(x.to_lattice_vector()) {
+            return Err(OIntError::InvalidHalfInteger);
+        }
+        Ok(x)
+    }
+
+    // Set the stored (*2) fields directly, e.g. from external code that already
+    // works in doubled coordinates. Bypasses the same-parity check of from_halves.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn from_raw(a: i32, b: i32, c: i32, d: i32, e: i32, f: i32, g: i32, h: i32) -> Self {
+        OInt { a, b, c, d, e, f, g, h }
+    }
+
+    // The stored (*2) fields, as accepted by from_raw
+    pub fn raw_components(self) -> [i32; 8] {
+        [self.a, self.b, self.c, self.d, self.e, self.f, self.g, self.h]
+    }
+
+    // Same as raw_components; pairs with from_components for coordinate maps
+    pub fn components(self) -> [i32; 8] {
+        self.raw_components()
+    }
+
+    // The stored (*2) fields as little-endian i32s, matching the #[repr(C)]
+    // layout on LE targets
+    pub fn to_le_bytes(self) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        write_le(&self.raw_components(), &mut out);
+        out
+    }
+
+    // Inverse of to_le_bytes; like from_raw, no parity or lattice check
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        let mut c = [0i32; 8];
+        read_le(&bytes, &mut c);
+        OInt::from_raw(c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7])
+    }
+
+    // Exactly eight stored (*2) values in the order of components(), checked
+    // for parity like from_halves
+    pub fn from_components<I: IntoIterator<Item = i32>>(iter: I) -> Result<Self, OIntError> {
+        let [a, b, c, d, e, f, g, h] = collect_array(iter).ok_or(OIntError::InvalidFormat)?;
+        OInt::from_halves(a, b, c, d, e, f, g, h)
+    }
+
+    // The Cayley-Dickson pair (p, q) = p + q·e4, i.e. p holds the components
+    // of 1, e1, e2, e3 and q those of e4..e7. Both types store *2 values, so
+    // the fields carry over unchanged.
+    pub const fn from_quaternion_pair(p: HInt, q: HInt) -> Self {
+        OInt::from_raw(p.a, p.b, p.c, p.d, q.a, q.b, q.c, q.d)
+    }
+
+    pub const fn to_quaternion_pair(self) -> (HInt, HInt) {
+        (HInt::from_raw(self.a, self.b, self.c, self.d), HInt::from_raw(self.e, self.f, self.g, self.h))
+    }
+
+    // The four coordinate planes (1, e1), (e2, e3), (e4, e5), (e6, e7) as
+    // Gaussian integers. Half-integer components do not fit a CInt, so these
+    // are the stored (*2) values; from_complex_projections takes them back.
+    pub const fn complex_projections(self) -> [CInt; 4] {
+        [CInt::new(self.a, self.b), CInt::new(self.c, self.d), CInt::new(self.e, self.f), CInt::new(self.g, self.h)]
+    }
+
+    pub const fn from_complex_projections(planes: [CInt; 4]) -> Self {
+        let [p, q, r, s] = planes;
+        OInt::from_raw(p.a, p.b, q.a, q.b, r.a, r.b, s.a, s.b)
+    }
+
+    pub const fn zero() -> Self {
         OInt::new(0, 0, 0, 0, 0, 0, 0, 0)
     }
 
-    pub fn one() -> Self {
+    pub const fn one() -> Self {
         OInt::new(1, 0, 0, 0, 0, 0, 0, 0)
     }
 
-    pub fn e1() -> Self { OInt::new(0, 1, 0, 0, 0, 0, 0, 0) }
-    pub fn e2() -> Self { OInt::new(0, 0, 1, 0, 0, 0, 0, 0) }
-    pub fn e3() -> Self { OInt::new(0, 0, 0, 1, 0, 0, 0, 0) }
-    pub fn e4() -> Self { OInt::new(0, 0, 0, 0, 1, 0, 0, 0) }
-    pub fn e5() -> Self { OInt::new(0, 0, 0, 0, 0, 1, 0, 0) }
-    pub fn e6() -> Self { OInt::new(0, 0, 0, 0, 0, 0, 1, 0) }
-    pub fn e7() -> Self { OInt::new(0, 0, 0, 0, 0, 0, 0, 1) }
+    pub const fn e1() -> Self { OInt::new(0, 1, 0, 0, 0, 0, 0, 0) }
+    pub const fn e2() -> Self { OInt::new(0, 0, 1, 0, 0, 0, 0, 0) }
+    pub const fn e3() -> Self { OInt::new(0, 0, 0, 1, 0, 0, 0, 0) }
+    pub const fn e4() -> Self { OInt::new(0, 0, 0, 0, 1, 0, 0, 0) }
+    pub const fn e5() -> Self { OInt::new(0, 0, 0, 0, 0, 1, 0, 0) }
+    pub const fn e6() -> Self { OInt::new(0, 0, 0, 0, 0, 0, 1, 0) }
+    pub const fn e7() -> Self { OInt::new(0, 0, 0, 0, 0, 0, 0, 1) }
+
+    pub const UNIT_COUNT: usize = 240;
+
+    // The 240 units by index: ±1, ±e_1, ..., ±e_7 (0..16), then 16 sign
+    // patterns for each of the 14 half-integer quadruples. None past UNIT_COUNT.
+    pub fn unit(index: usize) -> Option<Self> {
+        let mut c = [0i32; 8];
+        if index < 16 {
+            c[index / 2] = if index.is_multiple_of(2) { 2 } else { -2 };
+        } else if index < Self::UNIT_COUNT {
+            let quad = UNIT_QUADS[(index - 16) / 16];
+            let signs = (index - 16) % 16;
+            for (k, &slot) in quad.iter().enumerate() {
+                c[slot] = if signs & (1 << k) == 0 { 1 } else { -1 };
+            }
+        } else {
+            return None;
+        }
+        Some(OInt::from_raw(c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn units() -> Vec<Self> {
+        (0..Self::UNIT_COUNT).filter_map(Self::unit).collect()
+    }
 
     pub fn is_zero(self) -> bool {
         self.a == 0 && self.b == 0 && self.c == 0 && self.d == 0
@@ -150,6 +320,26 @@ impl OInt {
         self.norm_squared() == 1
     }
 
+    // Every component an integer: all stored (*2) values even
+    pub fn is_integer(self) -> bool {
+        self.raw_components().iter().all(|x| x % 2 == 0)
+    }
+
+    // Every component in Z + 1/2: all stored values odd
+    pub fn is_half_integer(self) -> bool {
+        self.raw_components().iter().all(|x| x % 2 != 0)
+    }
+
+    // Membership in the integral octonions, the ring that unit() and Mul
+    // work in: Z⁸ plus halves of the [8,4,4] code spanned by UNIT_QUADS, so
+    // the odd stored components must be none, all eight or one quadruple.
+    // one() and all 240 units pass. This is not the even-sum lattice model
+    // of is_valid_e8_element and is_in_lattice.
+    pub fn is_integral_octonion(self) -> bool {
+        let odd = self.raw_components().iter().rev().fold(0u8, |m, x| m << 1 | (x % 2 != 0) as u8);
+        odd == 0 || odd == 0xFF || UNIT_QUADS.iter().any(|q| q.iter().fold(0u8, |m, &k| m | 1 << k) == odd)
+    }
+
     pub fn conj(self) -> Self {
         OInt {
             a: self.a,
@@ -163,6 +353,13 @@ impl OInt {
         }
     }
 
+    // conj() in place: negates e1..e7
+    pub fn conj_mut(&mut self) {
+        for x in [&mut self.b, &mut self.c, &mut self.d, &mut self.e, &mut self.f, &mut self.g, &mut self.h] {
+            *x = -*x;
+        }
+    }
+
     pub fn norm_squared(self) -> u64 {
         let components = [self.a, self.b, self.c, self.d, self.e, self.f, self.g, self.h];
         let sum: i64 = components.iter()
@@ -171,24 +368,101 @@ impl OInt {
         (sum / 4) as u64  // Divide by 4 for *2 storage
     }
 
+    // Lossy floating companion to norm_squared: the Euclidean length |x|
+    #[cfg(feature = "std")]
+    pub fn magnitude(self) -> f64 {
+        (self.norm_squared() as f64).sqrt()
+    }
+
+    // N(a * b) == N(a) * N(b), where N(x) = x * conj(x). Every composition
+    // algebra satisfies this, so a false result means a sign error in the
+    // multiplication table. Compared in u128 so large inputs cannot wrap.
+    pub fn check_norm_multiplicative(a: Self, b: Self) -> bool {
+        (a * b).norm_squared() as u128 == a.norm_squared() as u128 * b.norm_squared() as u128
+    }
+
+    // check_norm_multiplicative over every pair; true for an empty slice
+    pub fn norm_is_multiplicative_over(samples: &[(Self, Self)]) -> bool {
+        samples.iter().all(|&(a, b)| Self::check_norm_multiplicative(a, b))
+    }
+
+    // True iff a and b are nonzero with a * b = 0. The octonions have no zero
+    // divisors, so a witness means a corrupted multiplication table. A product
+    // that overflows is nonzero and so never a witness.
+    pub fn has_zero_divisor_witness(a: Self, b: Self) -> bool {
+        !a.is_zero() && !b.is_zero() && a.checked_mul(b).is_ok_and(|p| p.is_zero())
+    }
+
+    // Polarization of the norm form: N(a + b) - N(a) - N(b), twice the
+    // lattice_dot fraction and always an integer. Computed as Σ stored
+    // products / 2.
+    pub fn bilinear_form(a: Self, b: Self) -> i64 {
+        let (x, y) = (a.raw_components(), b.raw_components());
+        let sum: i64 = x.iter().zip(&y).map(|(&p, &q)| p as i64 * q as i64).sum();
+        sum / 2
+    }
+
+    pub fn real_part(self) -> f64 {
+        self.a as f64 / 2.0
+    }
+
+    // Re(x) as a reduced (numerator, denominator) pair, with denominator 2
+    // for half-integer values
+    pub fn scalar(self) -> (i64, u64) {
+        halve(self.a)
+    }
+
+    // Reduced trace t(x) = x + conj(x) = 2·Re(x), which is the stored scalar
+    // and the linear coefficient of the minimal polynomial
+    pub fn trace(self) -> i64 {
+        self.a as i64
+    }
+
+    // n(x) = x·conj(x), the constant coefficient of the minimal polynomial
+    pub fn reduced_norm(self) -> i64 {
+        self.norm_squared() as i64
+    }
+
+    // Every octonion satisfies x² - t(x)·x + n(x) = 0; only powers of x are
+    // involved, so this holds despite non-associativity. Overflow when t(x),
+    // n(x) or a product does not fit the i32 storage.
+    pub fn satisfies_minimal_polynomial(self) -> Result<bool, OIntError> {
+        let t = OInt::from_integer(self.trace())?;
+        let n = OInt::from_integer(self.reduced_norm())?;
+        Ok((self.checked_mul(self)? - t.checked_mul(self)? + n).is_zero())
+    }
+
+    // The rational integer n, or Overflow if 2n does not fit the storage
+    fn from_integer(n: i64) -> Result<Self, OIntError> {
+        let raw = i32::try_from(n).ok().and_then(|n| n.checked_mul(2)).ok_or(OIntError::Overflow)?;
+        Ok(OInt::from_raw(raw, 0, 0, 0, 0, 0, 0, 0))
+    }
+
+    // Norm of the pure imaginary part: N(x) = Re(x)² + imaginary_norm_squared,
+    // as a reduced (numerator, denominator) pair (a multiple of 1/4 in general)
+    pub fn imaginary_norm_squared(self) -> (u64, u64) {
+        let sum: i64 = [self.b, self.c, self.d, self.e, self.f, self.g, self.h].iter()
+            .map(|&x| (x as i64) * (x as i64))
+            .sum();
+        reduce_ratio(sum as u128, 4)
+    }
+
+    // Right division, self = q * d + r, rounding self * conj(d) / N(d) per
+    // component; see div_rem_left for d * q + r
+    #[cfg(feature = "std")]
     pub fn div_rem(self, d: Self) -> Result<(Self, Self), OIntError> {
         if d.is_zero() {
             return Err(OIntError::DivisionByZero);
         }
 
         let d_norm = d.norm_squared() as i64;
-        let d_conj = d.conj();
-        let num_prod = self * d_conj;
+        // self * conj(d) in i64 (stored *4), as it may exceed the i32 range
+        let components = self.mul_cd_wide(d.conj());
 
         // Round each component
-        let components = [
-            num_prod.a, num_prod.b, num_prod.c, num_prod.d,
-            num_prod.e, num_prod.f, num_prod.g, num_prod.h
-        ];
-
         let q_components: Vec<i32> = components.iter()
             .map(|&x| {
-                let val = (x as f64) / (d_norm as f64 * 2.0);
+                let val = (x as f64) / (d_norm as f64 * 4.0);
                 (val.round() * 2.0) as i32
             })
             .collect();
@@ -204,10 +478,39 @@ impl OInt {
             h: q_components[7],
         };
 
+        let r = self - (q * d);
+        debug_assert!(self.div_rem_holds(d, q, r), "OInt div_rem: self != q * d + r");
+        Ok((q, r))
+    }
+
+    // self == q * d + r on the stored values, with the product kept wide
+    #[cfg(feature = "std")]
+    fn div_rem_holds(self, d: Self, q: Self, r: Self) -> bool {
+        let (wide, r, s) = (q.mul_cd_wide(d), r.raw_components(), self.raw_components());
+        (0..8).all(|k| wide[k] / 2 + r[k] as i64 == s[k] as i64)
+    }
+
+    // Division without floating point: round(num / (4 * N(d))) per component,
+    // with self * conj(d) kept in i64 so it may exceed the i32 range
+    pub fn div_rem_int(self, d: Self) -> Result<(Self, Self), OIntError> {
+        if d.is_zero() {
+            return Err(OIntError::DivisionByZero);
+        }
+
+        let den = 4 * d.norm_squared() as i64;
+        let num = self.mul_cd_wide(d.conj());
+
+        let mut q = [0i32; 8];
+        for (qk, &x) in q.iter_mut().zip(num.iter()) {
+            *qk = num_utils::round_div(x, den) as i32 * 2;
+        }
+
+        let q = OInt::from_raw(q[0], q[1], q[2], q[3], q[4], q[5], q[6], q[7]);
         let r = self - (q * d);
         Ok((q, r))
     }
 
+    #[cfg(feature = "std")]
     pub fn div_exact(self, d: Self) -> Result<Self, OIntError> {
         let (q, r) = self.div_rem(d)?;
         if r.is_zero() {
@@ -217,22 +520,59 @@ impl OInt {
         }
     }
 
+    // div_rem divides on the right: self = q * d + r, with q near self * d⁻¹.
+    // This is the same as div_rem, under a name that says so.
+    #[cfg(feature = "std")]
+    pub fn div_rem_right(self, d: Self) -> Result<(Self, Self), OIntError> {
+        self.div_rem(d)
+    }
+
+    // Left division: self = d * q + r, with q near d⁻¹ * self. Conjugation
+    // reverses products, so this is div_rem on conj(self) and conj(d) with
+    // the results conjugated back; N(r) is bounded exactly as for div_rem.
+    #[cfg(feature = "std")]
+    pub fn div_rem_left(self, d: Self) -> Result<(Self, Self), OIntError> {
+        let (q, r) = self.conj().div_rem(d.conj())?;
+        Ok((q.conj(), r.conj()))
+    }
+
+    // q with self = q * d exactly
+    #[cfg(feature = "std")]
+    pub fn div_exact_right(self, d: Self) -> Result<Self, OIntError> {
+        self.div_exact(d)
+    }
+
+    // q with self = d * q exactly
+    #[cfg(feature = "std")]
+    pub fn div_exact_left(self, d: Self) -> Result<Self, OIntError> {
+        let (q, r) = self.div_rem_left(d)?;
+        if r.is_zero() {
+            Ok(q)
+        } else {
+            Err(OIntError::NotDivisible)
+        }
+    }
+
+    // Right division self * den⁻¹ = self * conj(den) / N(den). The left
+    // quotient den⁻¹ * self is den.inv_fraction()? * OIFraction::from(self).
     pub fn div_to_fraction(self, den: Self) -> Result<OIFraction, OIntError> {
         if den.is_zero() {
             return Err(OIntError::DivisionByZero);
         }
         Ok(OIFraction {
-            num: self,
+            num: self * den.conj(),
             den: den.norm_squared(),
         })
     }
 
+    // Lowest terms, unique per value as for CInt::reduce_fraction; the only
+    // extra rule is that num stays an integral octonion (is_integral_octonion)
     pub fn reduce_fraction(frac: OIFraction) -> OIFraction {
         let components = [
-            frac.num.a.abs() as u64, frac.num.b.abs() as u64,
-            frac.num.c.abs() as u64, frac.num.d.abs() as u64,
-            frac.num.e.abs() as u64, frac.num.f.abs() as u64,
-            frac.num.g.abs() as u64, frac.num.h.abs() as u64,
+            frac.num.a.unsigned_abs() as u64, frac.num.b.unsigned_abs() as u64,
+            frac.num.c.unsigned_abs() as u64, frac.num.d.unsigned_abs() as u64,
+            frac.num.e.unsigned_abs() as u64, frac.num.f.unsigned_abs() as u64,
+            frac.num.g.unsigned_abs() as u64, frac.num.h.unsigned_abs() as u64,
         ];
         
         let mut g = components[0];
@@ -245,8 +585,31 @@ impl OInt {
             return frac;
         }
 
+        let n = frac.num;
+        let divide = |g: u64| {
+            let g = g as i64;
+            let q = OInt::from_raw(
+                (n.a as i64 / g) as i32, (n.b as i64 / g) as i32,
+                (n.c as i64 / g) as i32, (n.d as i64 / g) as i32,
+                (n.e as i64 / g) as i32, (n.f as i64 / g) as i32,
+                (n.g as i64 / g) as i32, (n.h as i64 / g) as i32,
+            );
+            Some(q).filter(|q| q.is_integral_octonion())
+        };
+
+        // The stored components are *2, so dividing by an even g can leave odd
+        // halves off a unit quadruple; back off to g/2, which keeps every
+        // component even
+        let num = match divide(g) {
+            Some(num) => num,
+            None => {
+                g /= 2;
+                divide(g).unwrap_or(frac.num)
+            }
+        };
+
         OIFraction {
-            num: frac.num,
+            num,
             den: frac.den / g,
         }
     }
@@ -261,6 +624,34 @@ impl OInt {
         })
     }
 
+    // self^exp by repeated squaring; octonions are power-associative, so
+    // the grouping does not matter
+    pub fn pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut acc = OInt::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc * base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base * base;
+            }
+        }
+        acc
+    }
+
+    // self^exp in the field of fractions, reduced: a negative exponent raises
+    // inv_fraction() to |exp|, and exp == 0 gives 1/1
+    pub fn pow_frac(self, exp: i32) -> Result<OIFraction, OIntError> {
+        let base = if exp < 0 { self.inv_fraction()? } else { OIFraction::from(self) };
+        let mut acc = OIFraction::from(OInt::one());
+        for _ in 0..exp.unsigned_abs() {
+            acc = acc * base;
+        }
+        Ok(OInt::reduce_fraction(acc))
+    }
+
     pub fn inv_unit(self) -> Result<Self, OIntError> {
         if !self.is_unit() {
             return Err(OIntError::NoInverse);
@@ -268,13 +659,31 @@ impl OInt {
         Ok(self.conj())
     }
 
+    #[cfg(feature = "std")]
     pub fn gcd(mut a: Self, mut b: Self) -> Self {
         while !b.is_zero() {
             let (_, r) = a.div_rem(b).unwrap_or((Self::zero(), a));
             a = b;
             b = r;
         }
-        a.normalize()
+        a.canonical_associate()
+    }
+
+    // gcd folded left to right over the slice, with the conventions of
+    // CInt::gcd_many. The octonions are not associative, so the Euclidean
+    // steps depend on the order of the elements and reordering the slice can
+    // change the result; pass the elements in a fixed order for reproducible
+    // output.
+    #[cfg(feature = "std")]
+    pub fn gcd_many(xs: &[Self]) -> Self {
+        let mut acc = OInt::zero();
+        for &x in xs {
+            acc = OInt::gcd(acc, x);
+            if acc.is_unit() {
+                break;
+            }
+        }
+        acc
     }
 
     pub fn normalize(self) -> Self {
@@ -294,25 +703,80 @@ impl OInt {
         self
     }
 
-    pub fn associates(self) -> [Self; 8] {
-        let units = [
-            Self::one(),
-            -Self::one(),
-            Self::e1(),
-            -Self::e1(),
-            Self::e2(),
-            -Self::e2(),
-            Self::e3(),
-            -Self::e3(),
-        ];
+    // The larger of self and -self, comparing stored components
+    // lexicographically. Unlike HInt this cannot range over all units: without
+    // associativity u * (v * x) is in general not a unit multiple of x, and
+    // repeated left multiplication reaches most vectors of the same norm, so
+    // the 240 multiples do not split into classes. ±1 are the units whose
+    // action composes, which makes this a true invariant of {self, -self}.
+    pub fn canonical_associate(self) -> Self {
+        if (-self).raw_components() > self.raw_components() { -self } else { self }
+    }
+
+    // True when other = u * self for one of the 240 units. That relation is
+    // not transitive here (see canonical_associate), so the units are tried
+    // directly, after a norm check.
+    pub fn eq_up_to_unit(self, other: Self) -> bool {
+        self.norm_squared() == other.norm_squared()
+            && (0..Self::UNIT_COUNT).filter_map(Self::unit).any(|u| u * self == other)
+    }
 
-        let mut result = [Self::zero(); 8];
-        for (i, u) in units.iter().enumerate() {
-            result[i] = self * (*u);
+    // unit * self, a norm-preserving symmetry of the E8 lattice. As for HInt,
+    // self * unit is a different symmetry; and since multiplication is not
+    // associative, rotating by u then v need not equal rotating by v * u.
+    pub fn rotate(self, unit: Self) -> Result<Self, OIntError> {
+        if !unit.is_unit() {
+            return Err(OIntError::NotAUnit);
+        }
+        Ok(unit * self)
+    }
+
+    // rotate() applied to every point
+    #[cfg(feature = "std")]
+    pub fn apply_symmetry(points: &[Self], unit: Self) -> Result<Vec<Self>, OIntError> {
+        if !unit.is_unit() {
+            return Err(OIntError::NotAUnit);
+        }
+        Ok(points.iter().map(|&p| unit * p).collect())
+    }
+
+    // Every distinct unit * self over the 240 units: all 240 for nonzero
+    // self, since the octonions have no zero divisors
+    #[cfg(feature = "std")]
+    pub fn associates(self) -> Vec<Self> {
+        let mut result: Vec<Self> = Vec::with_capacity(Self::UNIT_COUNT);
+        for u in Self::units() {
+            let x = u * self;
+            if !result.contains(&x) {
+                result.push(x);
+            }
         }
         result
     }
 
+    // Parse comma-separated actual values, e.g. "1,1,0,0" or "0.5,0.5,0.5,0.5"
+    #[cfg(feature = "std")]
+    pub fn from_coords_str(s: &str) -> Result<Self, OIntError> {
+        let v = s.split(',')
+            .map(halves::parse)
+            .collect::<Option<Vec<i32>>>()
+            .ok_or(OIntError::InvalidFormat)?;
+        if v.len() != 8 {
+            return Err(OIntError::InvalidFormat);
+        }
+        Self::from_halves(v[0], v[1], v[2], v[3], v[4], v[5], v[6], v[7])
+    }
+
+    // Comma-separated actual values, readable by from_coords_str
+    #[cfg(feature = "std")]
+    pub fn to_coords_str(self) -> String {
+        [self.a, self.b, self.c, self.d, self.e, self.f, self.g, self.h]
+            .iter()
+            .map(|&x| halves::format(x))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
     pub fn to_float_components(self) -> (f64, f64, f64, f64, f64, f64, f64, f64) {
         (
             self.a as f64 / 2.0,
@@ -326,6 +790,42 @@ impl OInt {
         )
     }
 
+    // Every component of to_float_components within eps of the target
+    pub fn approx_eq_float_components(self, target: (f64, f64, f64, f64, f64, f64, f64, f64), eps: f64) -> bool {
+        let (a, b, c, d, e, f, g, h) = self.to_float_components();
+        [(a, target.0), (b, target.1), (c, target.2), (d, target.3),
+         (e, target.4), (f, target.5), (g, target.6), (h, target.7)]
+            .iter()
+            .all(|&(x, y)| x - y <= eps && y - x <= eps)
+    }
+
+    // Commutator [a,b] = a*b - b*a
+    pub fn commutator(a: Self, b: Self) -> Self {
+        a * b - b * a
+    }
+
+    // Associator [a,b,c] = (a*b)*c - a*(b*c)
+    pub fn associator(a: Self, b: Self, c: Self) -> Self {
+        (a * b) * c - a * (b * c)
+    }
+
+    // Mean of N([a,b,c]) over the samples, and how many triples associate
+    // (zero associator). The mean is 0.0 for an empty sample.
+    pub fn associator_norm_stats(samples: &[(Self, Self, Self)]) -> (f64, u64) {
+        let mut total = 0u128;
+        let mut associative = 0u64;
+        for &(a, b, c) in samples {
+            let n = OInt::associator(a, b, c).norm_squared();
+            total += n as u128;
+            if n == 0 {
+                associative += 1;
+            }
+        }
+
+        let mean = if samples.is_empty() { 0.0 } else { total as f64 / samples.len() as f64 };
+        (mean, associative)
+    }
+
     // Non-commutative check
     pub fn is_non_commutative_pair(a: Self, b: Self) -> bool {
         a * b != b * a
@@ -351,6 +851,12 @@ impl OInt {
         left1 == right1 && left2 == right2
     }
 
+    // Flexible law: (a*b)*a = a*(b*a). Implied by alternativity, so it holds
+    // for all octonions even though the algebra is not associative.
+    pub fn flexible_identity(a: Self, b: Self) -> bool {
+        (a * b) * a == a * (b * a)
+    }
+
     // Moufang identity: (a*b)*(c*a) = a*(b*c)*a
     pub fn moufang_identity(a: Self, b: Self, c: Self) -> bool {
         let ab = a * b;
@@ -362,6 +868,288 @@ impl OInt {
         
         left == right
     }
+
+    // Structure constants: e_i * e_j = sign * e_k, returned as (sign, k).
+    // Index 0 is the scalar unit e₀ = 1 and 1..=7 are e₁..e₇.
+    pub fn basis_product(i: usize, j: usize) -> (i32, usize) {
+        assert!(i < 8 && j < 8, "octonion basis index out of range: ({}, {})", i, j);
+        fano_plane::multiply_basis(i, j)
+    }
+
+    // self * other computed by the given kernel
+    pub fn mul_with(self, other: Self, strategy: MulStrategy) -> Self {
+        match strategy {
+            MulStrategy::Table => self.mul_table(other),
+            MulStrategy::CayleyDickson => self.mul_cd(other),
+        }
+    }
+
+    // Product via the Fano plane table: 64 signed basis products
+    pub fn mul_table(self, other: Self) -> Self {
+        let mut result = [0i64; 8];
+        let sa = self.to_i64_array();
+        let oa = other.to_i64_array();
+
+        for (i, &x) in sa.iter().enumerate() {
+            for (j, &y) in oa.iter().enumerate() {
+                let (sign, idx) = fano_plane::multiply_basis(i, j);
+                result[idx] += x * y * (sign as i64);
+            }
+        }
+
+        Self::from_doubled_product(result)
+    }
+
+    // Product via Cayley-Dickson doubling: (a,b)(c,d) = (ac - d̄b, da + bc̄)
+    // where a = (a,b,c,d) and b = (e,f,g,h) are the quaternion halves
+    pub fn mul_cd(self, other: Self) -> Self {
+        Self::from_doubled_product(self.mul_cd_wide(other))
+    }
+
+    // Cayley-Dickson product of the stored (*2) components, still *4
+    fn mul_cd_wide(self, other: Self) -> [i64; 8] {
+        use cayley_dickson::{quat_conj, quat_mul};
+
+        let sa = self.to_i64_array();
+        let oa = other.to_i64_array();
+        let (a, b) = ([sa[0], sa[1], sa[2], sa[3]], [sa[4], sa[5], sa[6], sa[7]]);
+        let (c, d) = ([oa[0], oa[1], oa[2], oa[3]], [oa[4], oa[5], oa[6], oa[7]]);
+
+        let ac = quat_mul(a, c);
+        let db = quat_mul(quat_conj(d), b);
+        let da = quat_mul(d, a);
+        let bc = quat_mul(b, quat_conj(c));
+
+        let mut result = [0i64; 8];
+        for k in 0..4 {
+            result[k] = ac[k] - db[k];
+            result[k + 4] = da[k] + bc[k];
+        }
+
+        result
+    }
+
+    fn to_i64_array(self) -> [i64; 8] {
+        [self.a as i64, self.b as i64, self.c as i64, self.d as i64,
+         self.e as i64, self.f as i64, self.g as i64, self.h as i64]
+    }
+
+    // Products of *2-stored values are *4; divide by 2 to keep *2 storage
+    fn from_doubled_product(result: [i64; 8]) -> Self {
+        Self::try_from_doubled_product(result).expect("OInt multiplication overflow")
+    }
+
+    fn try_from_doubled_product(result: [i64; 8]) -> Option<Self> {
+        let mut c = [0i32; 8];
+        for k in 0..8 {
+            c[k] = i32::try_from(result[k] / 2).ok()?;
+        }
+        Some(OInt::from_raw(c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]))
+    }
+}
+
+impl Default for OInt {
+    fn default() -> Self {
+        OInt::zero()
+    }
+}
+
+impl PartialEq for OIFraction {
+    fn eq(&self, other: &Self) -> bool {
+        let (x, y) = (self.normalized(), other.normalized());
+        x.num == y.num && x.den == y.den
+    }
+}
+
+impl core::hash::Hash for OIFraction {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        let x = self.normalized();
+        x.num.hash(state);
+        x.den.hash(state);
+    }
+}
+
+impl OIFraction {
+    // Canonical lowest-terms form
+    pub fn normalized(self) -> Self {
+        OInt::reduce_fraction(self)
+    }
+
+    // N(num) / den² as a reduced (numerator, denominator) pair; exact, with
+    // the intermediate den² held in u128
+    pub fn norm_squared(self) -> (u64, u64) {
+        reduce_ratio(self.num.norm_squared() as u128, self.den as u128 * self.den as u128)
+    }
+}
+
+impl Default for OIFraction {
+    fn default() -> Self {
+        OIFraction {
+            num: OInt::zero(),
+            den: 1,
+        }
+    }
+}
+
+impl From<OInt> for OIFraction {
+    fn from(x: OInt) -> Self {
+        OIFraction { num: x, den: 1 }
+    }
+}
+
+impl OIFraction {
+    // Rewrite over the common denominator lcm(self.den, other.den)
+    fn over_common_den(self, other: Self) -> (OInt, OInt, u64) {
+        let g = num_utils::integer_gcd(self.den, other.den);
+        let lcm = self.den / g * other.den;
+        // Scaling the stored components directly: OInt::new(k, ..) would
+        // itself overflow once 2k passes i32::MAX
+        let scale = |num: OInt, k: u64| {
+            i32::try_from(k).ok()
+                .and_then(|k| num.checked_scale(k))
+                .expect("OIFraction denominator overflow")
+        };
+        (scale(self.num, lcm / self.den), scale(other.num, lcm / other.den), lcm)
+    }
+}
+
+impl Add for OIFraction {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let (a, b, den) = self.over_common_den(rhs);
+        OInt::reduce_fraction(OIFraction { num: a + b, den })
+    }
+}
+
+impl Sub for OIFraction {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        let (a, b, den) = self.over_common_den(rhs);
+        OInt::reduce_fraction(OIFraction { num: a - b, den })
+    }
+}
+
+// The denominators are rational integers, which commute and associate with
+// everything, so (p/m)(q/n) = pq/(mn) with the numerators multiplied in the
+// order written
+impl Mul for OIFraction {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let den = self.den.checked_mul(rhs.den).expect("OIFraction denominator overflow");
+        OInt::reduce_fraction(OIFraction { num: self.num * rhs.num, den })
+    }
+}
+
+// Quaternions embed as the (1, e1, e2, e3) slots, the first Cayley-Dickson half
+impl From<HInt> for OInt {
+    fn from(q: HInt) -> Self {
+        OInt { a: q.a, b: q.b, c: q.c, d: q.d, e: 0, f: 0, g: 0, h: 0 }
+    }
+}
+
+impl From<CInt> for OInt {
+    fn from(z: CInt) -> Self {
+        OInt::from(HInt::from(z))
+    }
+}
+
+// Projects back to the quaternion half; fails unless e4..e7 are all zero
+impl TryFrom<OInt> for HInt {
+    type Error = OIntError;
+
+    fn try_from(o: OInt) -> Result<Self, OIntError> {
+        if o.e != 0 || o.f != 0 || o.g != 0 || o.h != 0 {
+            return Err(OIntError::NotInSubalgebra);
+        }
+        Ok(HInt::from_raw(o.a, o.b, o.c, o.d))
+    }
+}
+
+// Integer coordinates, through new (so stored doubled). Half-integer values
+// need from_halves instead.
+impl From<(i32, i32, i32, i32, i32, i32, i32, i32)> for OInt {
+    fn from((a, b, c, d, e, f, g, h): (i32, i32, i32, i32, i32, i32, i32, i32)) -> Self {
+        OInt::new(a, b, c, d, e, f, g, h)
+    }
+}
+
+impl From<[i32; 8]> for OInt {
+    fn from([a, b, c, d, e, f, g, h]: [i32; 8]) -> Self {
+        OInt::new(a, b, c, d, e, f, g, h)
+    }
+}
+
+impl OInt {
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.zip_raw(rhs, i32::checked_add)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.zip_raw(rhs, i32::checked_sub)
+    }
+
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        self.zip_raw(rhs, |x, y| Some(x.wrapping_add(y))).unwrap()
+    }
+
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.zip_raw(rhs, |x, y| Some(x.wrapping_sub(y))).unwrap()
+    }
+
+    // n * self by scaling each component, much cheaper than multiplying by n
+    // as a ring element; overflows like Add
+    pub fn scale(self, n: i32) -> Self {
+        self.map_raw(|x| Some(x * n)).unwrap()
+    }
+
+    pub fn checked_scale(self, n: i32) -> Option<Self> {
+        self.map_raw(|x| x.checked_mul(n))
+    }
+
+    // 2 * self, i.e. every stored value shifted left by one; overflows like Add
+    pub fn double(self) -> Self {
+        self.scale(2)
+    }
+
+    // self / 2, or None unless every stored value is even and the halves
+    // are still an integral octonion, so the mixed-parity units halve back too
+    pub fn try_halve(self) -> Option<Self> {
+        let [a, b, c, d, e, f, g, h] = self.raw_components();
+        if !self.is_integer() {
+            return None;
+        }
+        Some(OInt::from_raw(a / 2, b / 2, c / 2, d / 2, e / 2, f / 2, g / 2, h / 2))
+            .filter(|x| x.is_integral_octonion())
+    }
+
+    // Mul panics on overflow, like CInt; this reports it instead. Both
+    // strategies give the same product, so the Cayley-Dickson one is used.
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, OIntError> {
+        // Beyond this bound the i64 sums themselves could overflow, and since
+        // N(xy) = N(x)N(y) the result would be far outside i32 anyway
+        if max_abs(&self.raw_components()) * max_abs(&rhs.raw_components()) >= 1 << 60 {
+            return Err(OIntError::Overflow);
+        }
+        Self::try_from_doubled_product(self.mul_cd_wide(rhs)).ok_or(OIntError::Overflow)
+    }
+
+    fn zip_raw(self, rhs: Self, op: impl Fn(i32, i32) -> Option<i32>) -> Option<Self> {
+        let (x, y) = (self.raw_components(), rhs.raw_components());
+        let mut c = [0i32; 8];
+        for k in 0..8 {
+            c[k] = op(x[k], y[k])?;
+        }
+        Some(OInt::from_raw(c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]))
+    }
+
+    fn map_raw(self, op: impl Fn(i32) -> Option<i32>) -> Option<Self> {
+        let x = self.raw_components();
+        let mut c = [0i32; 8];
+        for k in 0..8 {
+            c[k] = op(x[k])?;
+        }
+        Some(OInt::from_raw(c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]))
+    }
 }
 
 impl Add for OInt {
@@ -399,30 +1187,17 @@ impl Sub for OInt {
 impl Mul for OInt {
     type Output = OInt;
     fn mul(self, other: OInt) -> OInt {
-        let mut result = [0i64; 8];
-        let sa = [self.a as i64, self.b as i64, self.c as i64, self.d as i64,
-                  self.e as i64, self.f as i64, self.g as i64, self.h as i64];
-        let oa = [other.a as i64, other.b as i64, other.c as i64, other.d as i64,
-                  other.e as i64, other.f as i64, other.g as i64, other.h as i64];
-
-        for i in 0..8 {
-            for j in 0..8 {
-                let (sign, idx) = fano_plane::multiply_basis(i, j);
-                result[idx] += sa[i] * oa[j] * (sign as i64);
-            }
-        }
+        self.mul_table(other)
+    }
+}
 
-        // Divide by 2 to maintain *2 storage
-        OInt {
-            a: (result[0] / 2) as i32,
-            b: (result[1] / 2) as i32,
-            c: (result[2] / 2) as i32,
-            d: (result[3] / 2) as i32,
-            e: (result[4] / 2) as i32,
-            f: (result[5] / 2) as i32,
-            g: (result[6] / 2) as i32,
-            h: (result[7] / 2) as i32,
-        }
+// a / b is the right quotient a * b⁻¹ (well defined because the octonions are
+// alternative: (a * b⁻¹) * b = a); for b⁻¹ * a use b.inv_fraction() first
+impl Div for OInt {
+    type Output = OIFraction;
+    // Panics on a zero divisor; div_to_fraction is the checked form
+    fn div(self, rhs: Self) -> OIFraction {
+        self.div_to_fraction(rhs).expect("OInt division by zero")
     }
 }
 
@@ -442,3 +1217,37 @@ impl Neg for OInt {
     }
 }
 
+// Stored (*2) component by position, 0 being the scalar part; indices past 7 panic
+impl Index<usize> for OInt {
+    type Output = i32;
+    fn index(&self, index: usize) -> &i32 {
+        match index {
+            0 => &self.a,
+            1 => &self.b,
+            2 => &self.c,
+            3 => &self.d,
+            4 => &self.e,
+            5 => &self.f,
+            6 => &self.g,
+            7 => &self.h,
+            _ => panic!("OInt component index out of range: {}", index),
+        }
+    }
+}
+
+impl IndexMut<usize> for OInt {
+    fn index_mut(&mut self, index: usize) -> &mut i32 {
+        match index {
+            0 => &mut self.a,
+            1 => &mut self.b,
+            2 => &mut self.c,
+            3 => &mut self.d,
+            4 => &mut self.e,
+            5 => &mut self.f,
+            6 => &mut self.g,
+            7 => &mut self.h,
+            _ => panic!("OInt component index out of range: {}", index),
+        }
+    }
+}
+
@@ -0,0 +1,98 @@
+// src/types/random.rs
+//
+// Random values for fuzzing and benchmarks (feature "rand")
+
+use crate::types::cint::CInt;
+use crate::types::hint::HInt;
+use crate::types::oint::OInt;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+// Components are drawn from [-RANGE, RANGE] so products stay well inside i32
+const RANGE: i32 = 1000;
+
+impl Distribution<CInt> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CInt {
+        CInt::new(rng.gen_range(-RANGE..=RANGE), rng.gen_range(-RANGE..=RANGE))
+    }
+}
+
+impl Distribution<HInt> for Standard {
+    // Integer or half-integer with equal probability, all components same parity
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> HInt {
+        let parity = rng.gen_range(0..2);
+        let mut c = [0i32; 4];
+        for x in c.iter_mut() {
+            *x = rng.gen_range(-RANGE..RANGE) * 2 + parity;
+        }
+        HInt::from_raw(c[0], c[1], c[2], c[3])
+    }
+}
+
+impl Distribution<OInt> for Standard {
+    // Integer or half-integer with equal probability, all components same parity
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> OInt {
+        let parity = rng.gen_range(0..2);
+        let mut c = [0i32; 8];
+        for x in c.iter_mut() {
+            *x = rng.gen_range(-RANGE..RANGE) * 2 + parity;
+        }
+        OInt::from_raw(c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7])
+    }
+}
+
+impl CInt {
+    // Uniform over the 4 units ±1, ±i
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        CInt::unit(rng.gen_range(0..CInt::UNIT_COUNT)).unwrap()
+    }
+}
+
+impl HInt {
+    // Uniform over the 24 Hurwitz units: ±1, ±i, ±j, ±k and (±1 ± i ± j ± k)/2
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        HInt::unit(rng.gen_range(0..HInt::UNIT_COUNT)).unwrap()
+    }
+
+}
+
+impl OInt {
+    // Uniform over the 240 units: ±1, ±e_i and the 224 half-integer units
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        OInt::unit(rng.gen_range(0..OInt::UNIT_COUNT)).unwrap()
+    }
+
+    // Uniform over the 240 minimal vectors of the E₈ lattice (norm 2 in the
+    // is_in_lattice convention, the nonzero part of vectors_within(2)): the
+    // 112 of shape (±1, ±1, 0, ..., 0) and the 128 of shape (±½, ..., ±½)
+    // with an even number of minus signs. Built directly from the index, so
+    // no enumeration or allocation is needed.
+    pub fn random_minimal_vector<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let index = rng.gen_range(0..240);
+        let mut c = [0i32; 8];
+
+        if index < 112 {
+            // The (index / 4)-th position pair j < k, and two sign bits
+            let (mut pair, signs) = (index / 4, index % 4);
+            let mut j = 0;
+            while pair >= 7 - j {
+                pair -= 7 - j;
+                j += 1;
+            }
+            c[j] = if signs & 1 == 0 { 2 } else { -2 };
+            c[j + 1 + pair] = if signs & 2 == 0 { 2 } else { -2 };
+        } else {
+            // Seven free signs; the last one makes the count of minus signs even
+            let bits = index - 112;
+            let mut negatives = 0u32;
+            for (n, x) in c.iter_mut().take(7).enumerate() {
+                let negative = (bits >> n) & 1 == 1;
+                negatives += negative as u32;
+                *x = if negative { -1 } else { 1 };
+            }
+            c[7] = if negatives.is_multiple_of(2) { 1 } else { -1 };
+        }
+
+        OInt::from_raw(c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7])
+    }
+}
@@ -0,0 +1,473 @@
+use entropy_hpc::{CInt, EInt, HInt, OInt};
+use entropy_hpc::types::cint::CIntError;
+use entropy_hpc::types::hint::HIntError;
+use entropy_hpc::types::oint::OIntError;
+
+#[test]
+fn test_trace_matches_x_plus_conj() {
+    let z = CInt::new(3, -4);
+    assert_eq!(z.trace(), (z + z.conj()).a as i64);
+    assert_eq!(z.scalar(), (3, 1));
+    assert_eq!(z.real_part(), 3.0);
+    assert_eq!(z.imaginary_norm_squared(), (16, 1));
+    // 2·i32::MAX does not fit an i32
+    assert_eq!(CInt::new(i32::MAX, 0).trace(), 2 * i32::MAX as i64);
+
+    let h = HInt::from_halves(3, 1, -1, 1).unwrap();
+    assert_eq!(h.trace() as f64, (h + h.conj()).real_part());
+    assert_eq!(h.scalar(), (3, 2));
+    assert_eq!(h.real_part(), 1.5);
+    assert_eq!(h.imaginary_norm_squared(), (3, 4));
+    // Re(x)² + imaginary_norm_squared = N(x): 9/4 + 3/4
+    assert_eq!(h.norm_squared(), 3);
+    assert_eq!(HInt::new(-2, 1, 0, 0).scalar(), (-2, 1));
+
+    let o = OInt::new(2, 1, 0, -1, 3, 0, 0, 1);
+    assert_eq!(o.trace() as f64, (o + o.conj()).real_part());
+    assert_eq!(o.trace(), 2 * 2);
+    assert_eq!(o.scalar(), (2, 1));
+    assert_eq!(o.imaginary_norm_squared(), (12, 1));
+    // Re(x)² + imaginary_norm_squared = N(x): 4 + 12
+    assert_eq!(o.norm_squared(), 16);
+}
+
+#[test]
+fn test_default_is_zero() {
+    assert_eq!(CInt::default(), CInt::zero());
+    assert_eq!(HInt::default(), HInt::zero());
+    assert_eq!(OInt::default(), OInt::zero());
+
+    let mut v: Vec<OInt> = vec![OInt::one()];
+    v.resize_with(4, Default::default);
+    assert_eq!(v, vec![OInt::one(), OInt::zero(), OInt::zero(), OInt::zero()]);
+
+    let frac = entropy_hpc::types::cint::CIFraction::default();
+    assert!(frac.num.is_zero());
+    assert_eq!(frac.den, 1);
+}
+
+#[test]
+fn test_checked_and_wrapping_add_at_boundary() {
+    let max = CInt::new(i32::MAX, 0);
+    assert_eq!(max.checked_add(CInt::one()), None);
+    assert_eq!(max.checked_add(CInt::i()), Some(CInt::new(i32::MAX, 1)));
+    assert_eq!(max.wrapping_add(CInt::one()), CInt::new(i32::MIN, 0));
+    assert_eq!(CInt::new(i32::MIN, 0).checked_sub(CInt::one()), None);
+    assert_eq!(CInt::new(i32::MIN, 0).wrapping_sub(CInt::one()), max);
+
+    let emax = EInt::new(0, i32::MAX);
+    assert_eq!(emax.checked_add(EInt::omega()), None);
+    assert_eq!(emax.wrapping_add(EInt::omega()), EInt::new(0, i32::MIN));
+
+    // Doubled storage: i32::MAX / 2 is the largest whole component
+    let half_max = i32::MAX / 2;
+    let h = HInt::new(half_max, 0, 0, 0);
+    assert_eq!(h.checked_add(HInt::one()), None);
+    assert_eq!(h.checked_sub(HInt::one()), Some(HInt::new(half_max - 1, 0, 0, 0)));
+    assert_eq!(h.wrapping_add(HInt::one()).raw_components()[0], i32::MIN);
+
+    let o = OInt::new(0, 0, 0, 0, 0, 0, 0, -half_max);
+    assert_eq!(o.checked_sub(OInt::e7()), Some(OInt::new(0, 0, 0, 0, 0, 0, 0, -half_max - 1)));
+    assert_eq!(o.checked_sub(OInt::e7()).unwrap().checked_sub(OInt::e7()), None);
+    assert_eq!(o.checked_add(OInt::e7()), Some(OInt::new(0, 0, 0, 0, 0, 0, 0, 1 - half_max)));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_add_operator_overflow_panics_in_debug() {
+    let _ = CInt::new(i32::MAX, 0) + CInt::one();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_hint_add_operator_overflow_panics_in_debug() {
+    let h = HInt::new(i32::MAX / 2, 0, 0, 0);
+    let _ = h + h;
+}
+
+#[test]
+fn test_checked_mul_reports_overflow() {
+    // 40000² = 1.6e9 fits, but doubled storage holds at most i32::MAX / 2
+    let h = HInt::new(40000, 0, 0, 0);
+    assert_eq!(h.checked_mul(h), Err(HIntError::Overflow));
+    assert_eq!(h.checked_mul(HInt::new(0, 2, 0, 0)), Ok(HInt::new(0, 80000, 0, 0)));
+
+    let o = OInt::new(0, 0, 0, 0, 40000, 0, 0, 0);
+    assert_eq!(o.checked_mul(o), Err(OIntError::Overflow));
+    let x = OInt::new(3, -1, 0, 2, 0, 5, 1, 0);
+    let y = OInt::from_halves(1, 1, 1, 1, -1, 1, 1, 1).unwrap();
+    assert_eq!(x.checked_mul(y), Ok(x * y));
+
+    // Extreme components, where even the i64 intermediates would overflow
+    let big = OInt::from_raw(i32::MIN, 0, 0, 0, 0, 0, 0, i32::MIN);
+    assert_eq!(big.checked_mul(big), Err(OIntError::Overflow));
+    assert_eq!(HInt::from_raw(i32::MIN, 0, 0, 0).checked_mul(HInt::from_raw(i32::MIN, 0, 0, 0)), Err(HIntError::Overflow));
+}
+
+#[test]
+#[should_panic(expected = "OInt multiplication overflow")]
+fn test_oint_mul_operator_overflow_panics() {
+    let o = OInt::new(40000, 0, 0, 0, 0, 0, 0, 0);
+    let _ = o * o;
+}
+
+#[test]
+#[should_panic(expected = "HInt multiplication overflow")]
+fn test_hint_mul_operator_overflow_panics() {
+    let h = HInt::new(0, 40000, 0, 0);
+    let _ = h * h;
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_unit_groups() {
+    use std::collections::HashSet;
+
+    let c: HashSet<CInt> = CInt::units().into_iter().collect();
+    let h: HashSet<HInt> = HInt::units().into_iter().collect();
+    let o: HashSet<OInt> = OInt::units().into_iter().collect();
+    assert_eq!((c.len(), h.len(), o.len()), (4, 24, 240));
+
+    assert!(c.iter().all(|&u| u.norm_squared() == 1 && c.contains(&-u)));
+    assert!(h.iter().all(|&u| u.norm_squared() == 1 && h.contains(&-u)));
+    assert!(o.iter().all(|&u| u.norm_squared() == 1 && o.contains(&-u)));
+
+    // Closed under multiplication: a group for Z[i] and the Hurwitz order,
+    // a Moufang loop for the octonions
+    assert!(h.iter().all(|&x| h.iter().all(|&y| h.contains(&(x * y)))));
+    assert!(o.iter().all(|&x| o.iter().all(|&y| o.contains(&(x * y)))));
+
+    assert_eq!(OInt::unit(OInt::UNIT_COUNT), None);
+    assert_eq!(HInt::unit(0), Some(HInt::one()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_full_associate_classes() {
+    let h = HInt::new(3, -1, 2, 0);
+    let assoc = h.associates();
+    assert_eq!(assoc.len(), 24);
+    assert_eq!(HInt::UNIT_COUNT % assoc.len(), 0);
+    assert!(assoc.iter().all(|x| x.norm_squared() == h.norm_squared()));
+    assert_eq!(HInt::zero().associates(), vec![HInt::zero()]);
+
+    let o = OInt::new(1, 2, 0, -1, 0, 0, 3, 1);
+    let assoc = o.associates();
+    assert_eq!(assoc.len(), 240);
+    assert_eq!(OInt::UNIT_COUNT % assoc.len(), 0);
+    assert!(assoc.iter().all(|x| x.norm_squared() == o.norm_squared()));
+
+    // Left unit factors do not change the common right divisors, so the gcd
+    // of any associate is an associate of the original gcd
+    let d = HInt::new(1, 1, 0, 0);
+    let a = HInt::new(2, 1, 0, 1) * d;
+    let b = HInt::new(0, 3, -1, 1) * d;
+    let g = HInt::gcd(a, b);
+    let g_class = g.associates();
+    for x in a.associates() {
+        assert!(g_class.contains(&HInt::gcd(x, b)));
+    }
+}
+
+#[test]
+fn test_bilinear_form_polarizes_norm() {
+    let c = (CInt::new(3, -2), CInt::new(-1, 5));
+    assert_eq!(CInt::bilinear_form(c.0, c.0), 2 * c.0.norm_squared() as i64);
+    assert_eq!(CInt::bilinear_form(c.0, c.1), 2 * c.0.lattice_dot(c.1) as i64);
+    let polar = (c.0 + c.1).norm_squared() as i64 - c.0.norm_squared() as i64 - c.1.norm_squared() as i64;
+    assert_eq!(CInt::bilinear_form(c.0, c.1), polar);
+
+    let h = (HInt::from_halves(1, 3, -1, 1).unwrap(), HInt::new(2, 0, -1, 1));
+    assert_eq!(HInt::bilinear_form(h.0, h.0), 2 * h.0.norm_squared() as i64);
+    let polar = (h.0 + h.1).norm_squared() as i64 - h.0.norm_squared() as i64 - h.1.norm_squared() as i64;
+    assert_eq!(HInt::bilinear_form(h.0, h.1), polar);
+
+    let o = (
+        OInt::from_halves(1, -1, 1, 1, 3, -1, 1, -1).unwrap(),
+        OInt::from_halves(1, 1, 1, 1, -1, -1, 1, 1).unwrap(),
+    );
+    assert_eq!(OInt::bilinear_form(o.0, o.0), 2 * o.0.norm_squared() as i64);
+    let (num, den) = o.0.lattice_dot(o.1);
+    assert_eq!(OInt::bilinear_form(o.0, o.1) * den as i64, 2 * num);
+    let polar = (o.0 + o.1).norm_squared() as i64 - o.0.norm_squared() as i64 - o.1.norm_squared() as i64;
+    assert_eq!(OInt::bilinear_form(o.0, o.1), polar);
+
+    // Coordinates near 40000 square past i32; the form is returned in i64
+    let big = CInt::new(40_000, 40_000);
+    assert_eq!(CInt::bilinear_form(big, big), 6_400_000_000);
+    let big = HInt::new(40_000, 40_000, 0, 0);
+    assert_eq!(HInt::bilinear_form(big, big), 6_400_000_000);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_magnitude_and_lattice_distance() {
+    assert_eq!(CInt::new(3, 4).magnitude(), 5.0);
+    assert_eq!(CInt::new(1, 1).lattice_distance(CInt::new(4, -3)), 5.0);
+    assert_eq!(EInt::new(1, -1).magnitude(), 3f64.sqrt());
+    assert_eq!(EInt::new(2, 1).lattice_distance(EInt::new(1, 0)), 1.0);
+
+    assert_eq!(HInt::new(1, 2, 2, 4).magnitude(), 5.0);
+    assert_eq!(HInt::from_halves(1, 1, 1, 1).unwrap().magnitude(), 1.0);
+    assert_eq!(HInt::from_halves(1, 1, 1, 1).unwrap().lattice_distance(HInt::zero()), 1.0);
+
+    assert_eq!(OInt::new(1, 1, 1, 1, 1, 1, 1, 1).magnitude(), 8f64.sqrt());
+    assert_eq!(OInt::e3().lattice_distance(OInt::e5()), 2f64.sqrt());
+
+    // The distance has no i32 overflow where the squared version would
+    let far = OInt::new(40_000, 0, 0, 0, 0, 0, 0, 0);
+    assert_eq!(far.lattice_distance(-far), 80_000.0);
+
+    // Differences near 2^32 would overflow once squared in i64
+    let (lo, hi) = (CInt::new(i32::MIN, i32::MIN), CInt::new(i32::MAX, i32::MAX));
+    assert!((lo.lattice_distance(hi) / (u32::MAX as f64 * 2f64.sqrt()) - 1.0).abs() < 1e-12);
+    let (lo, hi) = (EInt::new(i32::MIN, 0), EInt::new(i32::MAX, 0));
+    assert_eq!(lo.lattice_distance(hi), u32::MAX as f64);
+}
+
+#[test]
+fn test_index_matches_fields() {
+    let mut c = CInt::new(3, -4);
+    assert_eq!((c[0], c[1]), (c.a, c.b));
+    c[1] = 7;
+    assert_eq!(c, CInt::new(3, 7));
+
+    let mut h = HInt::new(1, 2, 3, 4);
+    for k in 0..4 {
+        h[k] *= -1;
+    }
+    assert_eq!(h, HInt::new(-1, -2, -3, -4));
+    assert_eq!(h[3], h.d);
+
+    let mut o = OInt::zero();
+    for k in 0..8 {
+        o[k] = k as i32;
+    }
+    assert_eq!(o, OInt::from_raw(0, 1, 2, 3, 4, 5, 6, 7));
+    assert_eq!((o[4], o[7]), (o.e, o.h));
+}
+
+#[test]
+#[should_panic(expected = "OInt component index out of range: 8")]
+fn test_index_out_of_range_panics() {
+    let _ = OInt::one()[8];
+}
+
+#[test]
+fn test_components_round_trip() {
+    let c = CInt::new(3, -4);
+    assert_eq!(CInt::from_components(c.components()), Ok(c));
+    let h = HInt::from_halves(1, -3, 5, 1).unwrap();
+    assert_eq!(HInt::from_components(h.components()), Ok(h));
+    let o = OInt::new(1, 2, 0, -1, 0, 0, 3, 1);
+    assert_eq!(OInt::from_components(o.components()), Ok(o));
+
+    // Mapping over the coordinates
+    let tripled = OInt::from_components(o.components().into_iter().map(|x| 3 * x)).unwrap();
+    assert_eq!(tripled, OInt::new(3, 6, 0, -3, 0, 0, 9, 3));
+
+    assert_eq!(CInt::from_components([1, 2, 3]), Err(CIntError::InvalidFormat));
+    assert_eq!(HInt::from_components([2, 2, 2]), Err(HIntError::InvalidFormat));
+    assert_eq!(HInt::from_components([1, 2, 2, 2]), Err(HIntError::InvalidHalfInteger));
+}
+
+#[test]
+fn test_scale_matches_scalar_product() {
+    let c = CInt::new(3, -4);
+    assert_eq!(c.scale(3), c * CInt::new(3, 0));
+    let e = EInt::new(2, 5);
+    assert_eq!(e.scale(-2), e * EInt::new(-2, 0));
+    let h = HInt::from_halves(1, -3, 5, 1).unwrap();
+    assert_eq!(h.scale(3), h * HInt::new(3, 0, 0, 0));
+    let x = OInt::from_halves(1, -1, 1, 1, 3, -1, 1, -1).unwrap();
+    assert_eq!(x.scale(3), x * OInt::new(3, 0, 0, 0, 0, 0, 0, 0));
+    assert_eq!(x.scale(0), OInt::zero());
+
+    assert_eq!(x.checked_scale(5), Some(x.scale(5)));
+    assert_eq!(OInt::new(i32::MAX / 2, 0, 0, 0, 0, 0, 0, 0).checked_scale(2), None);
+    assert_eq!(CInt::new(i32::MAX, 0).checked_scale(-1), Some(CInt::new(-i32::MAX, 0)));
+    assert_eq!(CInt::new(i32::MIN, 0).checked_scale(-1), None);
+}
+
+#[test]
+fn test_conj_mut_matches_conj() {
+    let mut c = CInt::new(3, -4);
+    c.conj_mut();
+    assert_eq!(c, CInt::new(3, -4).conj());
+
+    let mut e = EInt::new(2, 5);
+    e.conj_mut();
+    assert_eq!(e, EInt::new(2, 5).conj());
+
+    let mut h = HInt::from_halves(1, -3, 5, 1).unwrap();
+    h.conj_mut();
+    assert_eq!(h, HInt::from_halves(1, -3, 5, 1).unwrap().conj());
+
+    let mut points = vec![OInt::new(1, 2, 3, 4, 5, 6, 7, 8), OInt::e7(), OInt::zero()];
+    let expected: Vec<OInt> = points.iter().map(|p| p.conj()).collect();
+    points.iter_mut().for_each(|x| x.conj_mut());
+    assert_eq!(points, expected);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_gcd_many() {
+    assert_eq!(CInt::gcd_many(&[]), CInt::zero());
+    assert_eq!(CInt::gcd_many(&[CInt::new(-3, 4)]), CInt::new(-3, 4).normalize());
+
+    let common = CInt::new(2, 1);
+    let xs = [common * CInt::new(3, 0), common * CInt::new(1, 4), common * CInt::new(-2, 5)];
+    assert_eq!(CInt::gcd_many(&xs), common.normalize());
+    assert!(CInt::gcd_many(&[CInt::new(3, 0), CInt::new(2, 1), CInt::new(5, 0)]).is_unit());
+
+    let d = HInt::new(1, 1, 0, 0);
+    let hs = [HInt::new(2, 1, 0, 1) * d, HInt::new(0, 3, -1, 1) * d, HInt::new(1, 0, 0, 0) * d * d];
+    let g = HInt::gcd_many(&hs);
+    assert_eq!(g.norm_squared(), d.norm_squared());
+    assert!(HInt::gcd_many(&[HInt::new(3, 0, 0, 0), HInt::new(1, 1, 0, 0)]).is_unit());
+    assert_eq!(HInt::gcd_many(&[]), HInt::zero());
+
+    let o = OInt::new(2, 0, 0, 0, 0, 0, 0, 0);
+    let g = OInt::gcd_many(&[o * OInt::new(3, 0, 0, 0, 0, 0, 0, 0), o * OInt::e5(), o]);
+    assert_eq!(g.norm_squared(), o.norm_squared());
+    assert!(OInt::gcd_many(&[OInt::new(3, 0, 0, 0, 0, 0, 0, 0), OInt::new(1, 1, 0, 0, 0, 0, 0, 0)]).is_unit());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_eq_up_to_unit() {
+    // -2 + i = i * (1 + 2i)
+    assert_eq!(CInt::i() * CInt::new(1, 2), CInt::new(-2, 1));
+    assert!(CInt::new(1, 2).eq_up_to_unit(CInt::new(-2, 1)));
+    assert!(CInt::new(1, 2).eq_up_to_unit(CInt::new(-1, -2)));
+    // Same norm, but 2 + i is the conjugate class, not an associate
+    assert!(!CInt::new(1, 2).eq_up_to_unit(CInt::new(2, 1)));
+    assert!(CInt::zero().eq_up_to_unit(CInt::zero()));
+
+    // Associates beyond ±self, which normalize() would not identify
+    let h = HInt::new(3, 1, 0, -2);
+    let u = HInt::from_halves(1, -1, 1, 1).unwrap();
+    assert!(h.eq_up_to_unit(u * h));
+    assert_ne!((u * h).normalize(), h.normalize());
+    assert!(!h.eq_up_to_unit(HInt::new(3, 1, 0, 2)));
+
+    let o = OInt::new(2, 1, 0, 0, -1, 0, 3, 0);
+    assert!(OInt::units().iter().all(|&u| o.eq_up_to_unit(u * o)));
+    assert!(!o.eq_up_to_unit(o.scale(2)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_canonical_associate() {
+    let z = CInt::new(-3, 5);
+    let c = z.canonical_associate();
+    assert!(z.associates().iter().all(|&x| x.canonical_associate() == c));
+    assert_eq!(c, CInt::new(5, 3));
+
+    let h = HInt::from_halves(3, 1, -5, 7).unwrap();
+    let c = h.canonical_associate();
+    assert!(HInt::units().iter().all(|&u| (u * h).canonical_associate() == c));
+    assert!(HInt::units().iter().all(|&u| u.canonical_associate() == HInt::one()));
+    assert_eq!(HInt::gcd(h, h.scale(3)), c);
+
+    // Only ±1 act as a group on OInt, so the class is {o, -o}
+    let o = OInt::new(2, 1, 0, 0, -1, 0, 3, 0);
+    assert_eq!(o.canonical_associate(), o);
+    assert_eq!((-o).canonical_associate(), o);
+    assert_eq!(OInt::zero().canonical_associate(), OInt::zero());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_rotate_by_unit() {
+    // i has order 4, so four quarter turns return every point of Z²
+    let z = CInt::new(3, -7);
+    let mut p = z;
+    for _ in 0..4 {
+        p = p.rotate(CInt::i()).unwrap();
+    }
+    assert_eq!(p, z);
+    assert_eq!(z.rotate(CInt::i()), Ok(CInt::new(7, 3)));
+    assert_eq!(z.rotate(CInt::new(1, 1)), Err(CIntError::NotAUnit));
+
+    let points = [z, CInt::new(1, 0), CInt::zero()];
+    let rotated = CInt::apply_symmetry(&points, -CInt::one()).unwrap();
+    assert_eq!(rotated, vec![-z, CInt::new(-1, 0), CInt::zero()]);
+    assert_eq!(CInt::apply_symmetry(&points, CInt::new(2, 0)), Err(CIntError::NotAUnit));
+
+    // Left and right multiplication are different symmetries of D4
+    let h = HInt::new(1, 2, 0, 0);
+    let j = HInt::new(0, 0, 1, 0);
+    assert_eq!(h.rotate(j), Ok(j * h));
+    assert_ne!(j * h, h * j);
+    assert_eq!(h.rotate(j).unwrap().norm_squared(), h.norm_squared());
+    assert_eq!(h.rotate(h), Err(HIntError::NotAUnit));
+
+    let o = OInt::new(2, 1, 0, 0, -1, 0, 3, 0);
+    let u = OInt::units()[100];
+    let rotated = OInt::apply_symmetry(&[o, -o], u).unwrap();
+    assert_eq!(rotated, vec![u * o, -(u * o)]);
+    assert_eq!(rotated[0].norm_squared(), o.norm_squared());
+    assert_eq!(o.rotate(o), Err(OIntError::NotAUnit));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_div_rem_left_and_right() {
+    let a = HInt::new(3, -1, 4, 2);
+    let d = HInt::new(1, 2, 0, 1);
+    let (ql, rl) = a.div_rem_left(d).unwrap();
+    let (qr, rr) = a.div_rem_right(d).unwrap();
+    assert_eq!(d * ql + rl, a);
+    assert_eq!(qr * d + rr, a);
+    assert!(rl.norm_squared() < d.norm_squared());
+    assert_eq!(a.div_rem_right(d), a.div_rem(d));
+
+    // x * d and d * x differ, so each side only divides its own product
+    let x = HInt::new(2, 0, -1, 3);
+    assert_ne!(x * d, d * x);
+    assert_eq!((d * x).div_exact_left(d), Ok(x));
+    assert_eq!((x * d).div_exact_right(d), Ok(x));
+    assert_ne!((d * x).div_rem_right(d).unwrap().0, x);
+
+    // A real divisor commutes with everything, so both sides agree
+    let three = HInt::new(3, 0, 0, 0);
+    assert_eq!(a.div_rem_left(three), a.div_rem_right(three));
+
+    let a = OInt::new(5, -2, 1, 0, 3, -1, 2, 4);
+    let d = OInt::new(1, 0, 2, 0, 0, 1, 0, -1);
+    let x = OInt::new(2, 1, 0, -1, 0, 0, 3, 1);
+    assert_ne!(x * d, d * x);
+    assert_eq!((d * x).div_exact_left(d), Ok(x));
+    assert_eq!((x * d).div_exact_right(d), Ok(x));
+    assert_eq!((d * x).div_exact_right(d), Err(OIntError::NotDivisible));
+    let (q, r) = a.div_rem_left(d).unwrap();
+    assert_eq!(d * q + r, a);
+
+    let two = OInt::new(2, 0, 0, 0, 0, 0, 0, 0);
+    assert_eq!(a.div_rem_left(two), a.div_rem_right(two));
+    assert_eq!(a.div_rem_left(OInt::zero()), Err(OIntError::DivisionByZero));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_div_rem_invariant_holds() {
+    // Each div_rem checks self == q * d + r in debug builds
+    let (q, r) = CInt::new(i32::MAX, 0).div_rem(CInt::new(2, 0)).unwrap();
+    // q * d = 2³¹ is not an i32, but the wide check still accepts it
+    assert_eq!((q, r), (CInt::new(1 << 30, 0), CInt::new(-1, 0)));
+
+    assert!(EInt::new(17, -5).div_rem(EInt::new(3, 4)).is_ok());
+    assert!(HInt::new(7, -3, 2, 9).div_rem(HInt::from_halves(1, 1, 1, 3).unwrap()).is_ok());
+    assert!(OInt::new(7, -3, 2, 9, 0, 1, -4, 6).div_rem(OInt::new(1, 1, 0, 0, 0, 0, 2, 0)).is_ok());
+}
+
+#[test]
+fn test_div_rem_quotient_past_i32() {
+    // The quotient 2³¹ does not fit an i32
+    assert_eq!(CInt::new(i32::MIN, 0).div_rem(CInt::new(-1, 0)), Err(CIntError::Overflow));
+    assert_eq!(CInt::new(0, i32::MIN).div_rem(CInt::new(-1, 0)), Err(CIntError::Overflow));
+    assert_eq!(CInt::new(i32::MIN, 0).div_rem(CInt::new(1, 0)), Ok((CInt::new(i32::MIN, 0), CInt::zero())));
+}
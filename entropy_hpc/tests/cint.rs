@@ -0,0 +1,208 @@
+use entropy_hpc::CInt;
+#[cfg(feature = "std")]
+use entropy_hpc::types::cint::CIntError;
+use std::collections::HashSet;
+
+fn residues(modulus: CInt) -> HashSet<CInt> {
+    let mut set = HashSet::new();
+    for a in -10..10 {
+        for b in -10..10 {
+            set.insert(CInt::new(a, b).rem_mod(modulus));
+        }
+    }
+    set
+}
+
+#[test]
+fn test_rem_mod_residue_systems() {
+    for (m, size) in [(CInt::new(1, 1), 2), (CInt::new(2, 1), 5), (CInt::new(3, 0), 9), (CInt::new(2, 2), 8)] {
+        let set = residues(m);
+        assert_eq!(set.len(), size);
+        assert_eq!(set.len() as u64, m.norm_squared());
+
+        for &r in &set {
+            // Each residue is its own representative and lies in its class
+            assert_eq!(r.rem_mod(m), r);
+            let x = r + CInt::new(4, -7) * m;
+            assert_eq!(x.rem_mod(m), r);
+        }
+    }
+
+    // Associate moduli define the same ideal
+    let z = CInt::new(17, -23);
+    assert_eq!(z.rem_mod(CInt::new(2, 1)), z.rem_mod(CInt::new(-1, 2)));
+}
+
+#[test]
+fn test_rem_mod_past_i32() {
+    use entropy_hpc::types::cint::CIntError;
+
+    // N(50000 + i) = 2_500_000_001 > 2^31, so the residue of -1 is N - 1
+    let m = CInt::new(50_000, 1);
+    assert_eq!(CInt::new(-1, 0).checked_rem_mod(m), Err(CIntError::Overflow));
+    assert_eq!(CInt::new(5, 0).checked_rem_mod(m), Ok(CInt::new(5, 0)));
+    assert_eq!(CInt::checked_mul_mod(CInt::new(-1, 0), CInt::one(), m), Err(CIntError::Overflow));
+    assert_eq!(CInt::one().checked_rem_mod(CInt::zero()), Err(CIntError::DivisionByZero));
+
+    // The largest moduli no longer overflow the lattice arithmetic
+    let m = CInt::new(i32::MIN, i32::MIN);
+    assert_eq!(CInt::new(3, 4).checked_rem_mod(m), Ok(CInt::new(3, 4)));
+}
+
+#[test]
+#[should_panic(expected = "CInt residue overflow")]
+fn test_rem_mod_overflow_panics() {
+    CInt::new(-1, 0).rem_mod(CInt::new(50_000, 1));
+}
+
+#[test]
+fn test_mul_mod_matches_reduced_product() {
+    let m = CInt::new(2, 1);
+    for a in -6..6 {
+        for b in -6..6 {
+            let x = CInt::new(a, b);
+            let y = CInt::new(b - 3, a + 1);
+            assert_eq!(CInt::mul_mod(x, y, m), (x * y).rem_mod(m));
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_inv_mod() {
+    let m = CInt::new(2, 1);
+    let one = CInt::one().rem_mod(m);
+    let mut count = 0;
+    for r in residues(m) {
+        if r.is_zero() {
+            assert_eq!(r.inv_mod(m), Err(CIntError::NoInverse));
+            continue;
+        }
+        let inv = r.inv_mod(m).unwrap();
+        assert_eq!(CInt::mul_mod(r, inv, m), one);
+        assert_eq!(inv.rem_mod(m), inv);
+        count += 1;
+    }
+    // (2 + i) is prime, so every nonzero residue is a unit
+    assert_eq!(count, 4);
+
+    let x = CInt::new(7, -3);
+    let big = CInt::new(11, 4);
+    assert_eq!(CInt::mul_mod(x, x.inv_mod(big).unwrap(), big), CInt::one().rem_mod(big));
+
+    // 2 = -i(1 + i)², so 1 + i shares a factor with it
+    assert_eq!(CInt::new(1, 1).inv_mod(CInt::new(2, 0)), Err(CIntError::NoInverse));
+    assert_eq!(x.inv_mod(CInt::zero()), Err(CIntError::DivisionByZero));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_crt() {
+    let value = CInt::new(5, 3);
+    let (m1, m2) = (CInt::new(1, 1), CInt::new(3, 0));
+    let x = CInt::crt(&[(value.rem_mod(m1), m1), (value.rem_mod(m2), m2)]).unwrap();
+    assert_eq!(x, value.rem_mod(m1 * m2));
+    assert_eq!(x.rem_mod(m1), value.rem_mod(m1));
+    assert_eq!(x.rem_mod(m2), value.rem_mod(m2));
+
+    // Three moduli of norms 5, 13 and 3² multiply to norm 585
+    let moduli = [CInt::new(2, 1), CInt::new(3, 2), CInt::new(3, 0)];
+    let value = CInt::new(-41, 17);
+    let pairs: Vec<_> = moduli.iter().map(|&m| (value.rem_mod(m), m)).collect();
+    let product = moduli.iter().fold(CInt::one(), |p, &m| p * m);
+    assert_eq!(CInt::crt(&pairs).unwrap(), value.rem_mod(product));
+
+    // 2 and 1 + i share the prime 1 + i
+    let shared = [(CInt::one(), CInt::new(2, 0)), (CInt::zero(), CInt::new(1, 1))];
+    assert_eq!(CInt::crt(&shared), Err(CIntError::NoInverse));
+}
+
+#[test]
+fn test_classify() {
+    use entropy_hpc::types::cint::GaussianKind;
+
+    assert_eq!(CInt::zero().classify(), GaussianKind::Zero);
+    assert_eq!(CInt::i().classify(), GaussianKind::Unit);
+    assert_eq!(CInt::new(-1, 0).classify(), GaussianKind::Unit);
+    assert_eq!(CInt::new(1, 1).classify(), GaussianKind::RamifiedPrime);
+    assert_eq!(CInt::new(1, -1).classify(), GaussianKind::RamifiedPrime);
+    assert_eq!(CInt::new(3, 0).classify(), GaussianKind::InertPrime);
+    assert_eq!(CInt::new(0, -7).classify(), GaussianKind::InertPrime);
+    assert_eq!(CInt::new(2, 1).classify(), GaussianKind::SplitPrime);
+    assert_eq!(CInt::new(-5, 4).classify(), GaussianKind::SplitPrime);
+    assert_eq!(CInt::new(6, 0).classify(), GaussianKind::Composite);
+    assert_eq!(CInt::new(2, 0).classify(), GaussianKind::Composite);
+    assert_eq!(CInt::new(5, 0).classify(), GaussianKind::Composite);
+    assert_eq!(CInt::new(3, 3).classify(), GaussianKind::Composite);
+
+    assert!(CInt::new(2, 1).is_prime());
+    assert!(!CInt::new(5, 0).is_prime());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_gaussian_primes_up_to() {
+    let small = CInt::gaussian_primes_up_to(10);
+    assert_eq!(small, vec![CInt::new(1, 1), CInt::new(1, 2), CInt::new(2, 1), CInt::new(3, 0)]);
+
+    // Prime ideals of norm <= 100: one over 2, two over each of the eleven
+    // primes p ≡ 1 mod 4 below 100, and the inert 3 and 7
+    let primes = CInt::gaussian_primes_up_to(100);
+    assert_eq!(primes.len(), 1 + 2 * 11 + 2);
+    assert!(primes.iter().all(|&z| z.is_prime() && z.normalize() == z && z.norm_squared() <= 100));
+    assert!(primes.windows(2).all(|w| w[0].norm_squared() <= w[1].norm_squared()));
+
+    assert!(CInt::gaussian_primes_up_to(1).is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_from_polar_rounded() {
+    use std::f64::consts::PI;
+
+    assert_eq!(CInt::from_polar_rounded(5.0, 0.0), CInt::new(5, 0));
+    assert_eq!(CInt::from_polar_rounded(5.0, PI / 2.0), CInt::new(0, 5));
+    assert_eq!(CInt::from_polar_rounded(3.0, PI), CInt::new(-3, 0));
+    assert_eq!(CInt::from_polar_rounded(0.0, 1.0), CInt::zero());
+
+    // Rounding moves each coordinate by at most 1/2, so |z| - r is within √2/2
+    for k in 0..64 {
+        let theta = 2.0 * PI * k as f64 / 64.0;
+        let z = CInt::from_polar_rounded(20.0, theta);
+        let radius = (z.norm_squared() as f64).sqrt();
+        assert!((radius - 20.0).abs() <= 0.5f64.sqrt());
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_div_rem_minimal_remainder_at_ties() {
+    // 3 / 2 = 1.5 exactly: quotients 1 and 2 leave remainders 1 and -1
+    let (q, r) = CInt::new(3, 0).div_rem(CInt::new(2, 0)).unwrap();
+    assert_eq!(q * CInt::new(2, 0) + r, CInt::new(3, 0));
+    assert_eq!(r.norm_squared(), 1);
+
+    // (5 + 3i) / 2 = 2.5 + 1.5i ties in both coordinates
+    let (a, d) = (CInt::new(5, 3), CInt::new(2, 0));
+    let (q, r) = a.div_rem(d).unwrap();
+    assert_eq!(q * d + r, a);
+    for da in -1..=1 {
+        for db in -1..=1 {
+            let other = a - (q + CInt::new(da, db)) * d;
+            assert!(r.norm_squared() <= other.norm_squared());
+        }
+    }
+
+    // Quotient real part -0.5 for a non-real divisor: (1 + 2i) / (1 + i) = (3 + i) / 2
+    let (a, d) = (CInt::new(-1, -2), CInt::new(1, 1));
+    let (q, r) = a.div_rem(d).unwrap();
+    assert_eq!(q * d + r, a);
+    assert!(2 * r.norm_squared() <= d.norm_squared());
+
+    // Numerators beyond f64's 53-bit mantissa are rounded exactly
+    let a = CInt::new((1 << 30) + 7, -(1 << 30) + 3);
+    let d = CInt::new((1 << 25) + 1, (1 << 24) + 3);
+    let (q, r) = a.div_rem(d).unwrap();
+    assert_eq!(q * d + r, a);
+    assert!(2 * r.norm_squared() <= d.norm_squared());
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "num-complex")]
+
+use entropy_hpc::CInt;
+use entropy_hpc::types::cint::CIntError;
+use num_complex::Complex;
+
+#[test]
+fn test_to_complex() {
+    assert_eq!(CInt::new(3, 4).to_complex(), Complex::new(3.0, 4.0));
+    assert_eq!(CInt::new(-1, 0).to_complex(), Complex::new(-1.0, 0.0));
+}
+
+#[test]
+fn test_try_from_complex_rounds() {
+    let z = CInt::try_from(Complex::new(3.4, 4.6)).unwrap();
+    assert_eq!(z, CInt::new(3, 5));
+
+    let (z, err) = CInt::from_complex_rounded(Complex::new(3.4, 4.6)).unwrap();
+    assert_eq!(z, CInt::new(3, 5));
+    assert!((err - (0.4f64 * 0.4 + 0.4 * 0.4).sqrt()).abs() < 1e-12);
+
+    assert_eq!(CInt::try_from(Complex::new(f64::NAN, 0.0)), Err(CIntError::Overflow));
+    assert_eq!(CInt::try_from(Complex::new(1e12, 0.0)), Err(CIntError::Overflow));
+}
+
+#[test]
+fn test_approx_eq_complex() {
+    let z = CInt::new(3, -4);
+    assert!(z.approx_eq_complex(z.to_complex(), 1e-9));
+    assert!(z.approx_eq_complex(Complex::new(3.0 + 1e-12, -4.0), 1e-9));
+    assert!(!z.approx_eq_complex(Complex::new(3.0, -4.1), 1e-9));
+
+    let w = Complex::new(0.1 + 0.2, 0.0) * Complex::new(10.0, 0.0);
+    assert!(CInt::new(3, 0).approx_eq_complex(w, 1e-9));
+}
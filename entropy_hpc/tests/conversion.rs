@@ -0,0 +1,94 @@
+use entropy_hpc::{CInt, HInt, OInt};
+
+#[test]
+fn test_embeddings_preserve_products_and_norms() {
+    let x = CInt::new(3, -4);
+    let y = CInt::new(1, 2);
+
+    assert_eq!(HInt::from(x * y), HInt::from(x) * HInt::from(y));
+    assert_eq!(OInt::from(x * y), OInt::from(x) * OInt::from(y));
+    assert_eq!(HInt::from(x).norm_squared(), x.norm_squared());
+    assert_eq!(OInt::from(x).norm_squared(), x.norm_squared());
+
+    let p = HInt::from_halves(1, 3, -1, 1).unwrap();
+    let q = HInt::new(2, 0, 1, -1);
+    assert_eq!(OInt::from(p * q), OInt::from(p) * OInt::from(q));
+    assert_eq!(OInt::from(p).norm_squared(), p.norm_squared());
+
+    assert_eq!(OInt::from(CInt::i()), OInt::e1());
+    assert_eq!(OInt::from(HInt::k()), OInt::e3());
+}
+
+#[test]
+fn test_down_conversions() {
+    use entropy_hpc::types::hint::HIntError;
+    use entropy_hpc::types::oint::OIntError;
+
+    let o = OInt::new(1, 2, 3, 4, 0, 0, 0, 0);
+    let h = HInt::try_from(o).unwrap();
+    assert_eq!(h, HInt::new(1, 2, 3, 4));
+    assert_eq!(OInt::from(h), o);
+
+    let half = OInt::from(HInt::from_halves(1, 1, 1, 1).unwrap());
+    assert_eq!(HInt::try_from(half), Ok(HInt::from_halves(1, 1, 1, 1).unwrap()));
+
+    let z = CInt::try_from(HInt::new(5, -6, 0, 0)).unwrap();
+    assert_eq!(z, CInt::new(5, -6));
+
+    assert_eq!(HInt::try_from(OInt::new(1, 0, 0, 0, 1, 0, 0, 0)), Err(OIntError::NotInSubalgebra));
+    assert_eq!(CInt::try_from(HInt::new(1, 0, 1, 0)), Err(HIntError::NotInSubalgebra));
+    assert_eq!(CInt::try_from(HInt::from_halves(1, 1, 1, 1).unwrap()), Err(HIntError::NotInSubalgebra));
+}
+
+#[test]
+fn test_tuple_and_array_conversions() {
+    assert_eq!(CInt::from((3, -4)), CInt::new(3, -4));
+    assert_eq!(CInt::from([3, -4]), CInt::new(3, -4));
+    let t: (i32, i32) = CInt::new(3, -4).into();
+    assert_eq!(t, (3, -4));
+    assert_eq!(CInt::from(t), CInt::new(3, -4));
+
+    assert_eq!(HInt::from((1, 2, 3, 4)), HInt::new(1, 2, 3, 4));
+    assert_eq!(HInt::from([1, 2, 3, 4]), HInt::new(1, 2, 3, 4));
+    // The stored doubled vector is only reachable by name, so no From pair
+    // looks like a round trip while doubling
+    assert_eq!(HInt::new(1, 2, 3, 4).to_lattice_vector(), (2, 4, 6, 8));
+
+    assert_eq!(OInt::from((1, 0, 0, 0, 0, 0, 0, -1)), OInt::new(1, 0, 0, 0, 0, 0, 0, -1));
+    assert_eq!(OInt::from([1, 0, 0, 0, 0, 0, 0, -1]), OInt::new(1, 0, 0, 0, 0, 0, 0, -1));
+    assert_eq!(OInt::from_halves(1, 1, 1, 1, 1, 1, 1, 1).unwrap().to_lattice_vector(), (1, 1, 1, 1, 1, 1, 1, 1));
+}
+
+#[test]
+fn test_approx_eq_float_components() {
+    let h = HInt::from_halves(1, -3, 5, 1).unwrap();
+    assert!(h.approx_eq_float_components(h.to_float_components(), 1e-9));
+    assert!(h.approx_eq_float_components((0.5, -1.5, 2.5 + 1e-12, 0.5), 1e-9));
+    assert!(!h.approx_eq_float_components((0.5, -1.5, 2.5, 0.6), 1e-9));
+
+    let o = OInt::from_halves(1, 1, 1, 1, 1, 1, 1, -1).unwrap();
+    assert!(o.approx_eq_float_components(o.to_float_components(), 1e-9));
+    assert!(!o.approx_eq_float_components((0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5), 1e-9));
+}
+
+#[test]
+fn test_le_bytes_round_trip() {
+    use std::mem::size_of;
+
+    assert_eq!(CInt::new(1, -2).to_le_bytes(), [1, 0, 0, 0, 0xfe, 0xff, 0xff, 0xff]);
+    for z in [CInt::zero(), CInt::new(i32::MIN, i32::MAX), CInt::new(-7, 12)] {
+        assert_eq!(CInt::from_le_bytes(z.to_le_bytes()), z);
+    }
+    assert_eq!(CInt::one().to_le_bytes().len(), size_of::<CInt>());
+
+    // HInt and OInt encode the stored doubled values
+    let h = HInt::from_halves(1, -3, 5, 1).unwrap();
+    assert_eq!(&h.to_le_bytes()[..4], &[1, 0, 0, 0]);
+    assert_eq!(HInt::from_le_bytes(h.to_le_bytes()), h);
+    assert_eq!(h.to_le_bytes().len(), size_of::<HInt>());
+
+    let o = OInt::from_raw(i32::MIN, 0, 2, -4, 6, 0, 0, i32::MAX - 1);
+    assert_eq!(OInt::from_le_bytes(o.to_le_bytes()), o);
+    assert_eq!(OInt::from_le_bytes([0; 32]), OInt::zero());
+    assert_eq!(o.to_le_bytes().len(), size_of::<OInt>());
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use entropy_hpc::{CInt, HInt, OInt};
 use entropy_hpc::simd::LatticeSimd;
 
@@ -89,7 +91,7 @@ fn test_complete_api_showcase() {
     println!("\n╔════════════════════════════════════════════════════════════════╗");
     println!("║  ✓ Types:    95 functions (algebra, fractions, properties)  ║");
     println!("║  ✓ Lattice:  24 functions (geometry + parity constraints)   ║");
-    println!("║  ✓ SIMD:     24 functions (batch processing A₂/D₄/E₈)       ║");
+    println!("║  ✓ SIMD:     24 functions (batch processing Z²/D₄/E₈)       ║");
     println!("║  ✓ Display:   1 impl (all 3 types)                         ║");
     println!("║  ─────────────────────────────────────────────────────────  ║");
     println!("║  TOTAL: 143 FUNCTIONS WORKING ✓                             ║");
@@ -0,0 +1,81 @@
+#![cfg(feature = "std")]
+
+use entropy_hpc::{CInt, HInt, OInt};
+
+#[test]
+fn test_to_latex() {
+    assert_eq!(CInt::new(3, 4).to_latex(), "3 + 4i");
+    assert_eq!(CInt::new(0, -1).to_latex(), "-i");
+
+    let h = HInt::from_halves(1, 1, 1, 1).unwrap();
+    assert_eq!(h.to_latex(), r"\frac{1}{2} + \frac{1}{2}\mathbf{i} + \frac{1}{2}\mathbf{j} + \frac{1}{2}\mathbf{k}");
+    assert_eq!(HInt::from_halves(-3, 1, 1, 1).unwrap().to_latex(),
+               r"-\frac{3}{2} + \frac{1}{2}\mathbf{i} + \frac{1}{2}\mathbf{j} + \frac{1}{2}\mathbf{k}");
+
+    let o = OInt::new(2, 0, -1, 0, 0, 0, 0, 3);
+    assert_eq!(o.to_latex(), "2 - e_{2} + 3e_{7}");
+    assert_eq!(OInt::zero().to_latex(), "0");
+
+    let frac = CInt::new(1, 0).div_to_fraction(CInt::new(1, 1)).unwrap();
+    assert_eq!(frac.to_latex(), r"\frac{1 - i}{2}");
+}
+
+#[test]
+fn test_display_half_components_use_raw_over_two() {
+    let h = HInt { a: 2, b: 3, c: 0, d: -5 };
+    assert_eq!(format!("{}", h), "1 + 3/2i - 5/2k");
+
+    let h = HInt::from_halves(-5, 1, 1, 3).unwrap();
+    assert_eq!(format!("{}", h), "-5/2 + 1/2i + 1/2j + 3/2k");
+
+    let o = OInt::from_halves(3, 1, 1, 1, 1, 1, 1, -5).unwrap();
+    assert_eq!(format!("{}", o), "3/2 + 1/2e₁ + 1/2e₂ + 1/2e₃ + 1/2e₄ + 1/2e₅ + 1/2e₆ - 5/2e₇");
+}
+
+#[test]
+fn test_display_skips_zero_terms() {
+    assert_eq!(format!("{}", OInt::e7()), "e₇");
+    assert_eq!(format!("{}", -OInt::e7()), "-e₇");
+    assert_eq!(format!("{}", OInt::zero()), "0");
+    assert_eq!(format!("{}", OInt::one()), "1");
+    assert_eq!(format!("{}", OInt::new(0, 0, -2, 0, 0, 1, 0, 0)), "-2e₂ + e₅");
+    assert_eq!(format!("{}", OInt::new(3, 0, 0, 0, 0, 0, 0, -1)), "3 - e₇");
+
+    assert_eq!(format!("{}", HInt::zero()), "0");
+    assert_eq!(format!("{}", HInt::new(0, 0, 0, -3)), "-3k");
+    assert_eq!(format!("{}", -HInt::j()), "-j");
+
+    let frac = HInt::one().div_to_fraction(HInt::new(1, 1, 0, 0)).unwrap();
+    assert_eq!(format!("{}", frac), "(1 - i) / 2");
+}
+
+#[test]
+fn test_display_width_and_flags() {
+    assert_eq!(format!("{:>10}", CInt::new(1, 2)), "    1 + 2i");
+    assert_eq!(format!("{:<10}|", CInt::new(1, 2)), "1 + 2i    |");
+    assert_eq!(format!("{:*^10}", CInt::new(1, 2)), "**1 + 2i**");
+    assert_eq!(format!("{:3}", CInt::new(1, 2)), "1 + 2i");
+
+    // Width counts chars, not bytes, so subscripts pad correctly
+    assert_eq!(format!("{:>5}", OInt::e7()), "   e₇");
+    assert_eq!(format!("{:>8}", -HInt::j()), "      -j");
+
+    // `+` marks nonnegative leading terms only
+    assert_eq!(format!("{:+}", HInt::new(1, 0, 0, -1)), "+1 - k");
+    assert_eq!(format!("{:+}", -OInt::e7()), "-e₇");
+    assert_eq!(format!("{:+9}|", CInt::new(1, 2)), "+1 + 2i  |");
+
+    let frac = HInt::one().div_to_fraction(HInt::new(1, 1, 0, 0)).unwrap();
+    assert_eq!(format!("{:>14}", frac), "   (1 - i) / 2");
+}
+
+#[test]
+fn test_raw_debug() {
+    let h = HInt::from_halves(1, 1, 1, 1).unwrap();
+    assert_eq!(h.raw_debug(), format!("HInt{{raw:[1,1,1,1], parity:odd, value:{}}}", h));
+    assert_eq!(HInt::new(1, 1, 1, 1).raw_debug(), format!("HInt{{raw:[2,2,2,2], parity:even, value:{}}}", HInt::new(1, 1, 1, 1)));
+    assert!(HInt::from_raw(1, 2, 0, 0).raw_debug().contains("parity:mixed"));
+
+    let o = OInt::new(-1, 0, 0, 0, 0, 0, 0, 3);
+    assert!(o.raw_debug().starts_with("OInt{raw:[-2,0,0,0,0,0,0,6], parity:even, value:"));
+}
@@ -0,0 +1,40 @@
+use entropy_hpc::EInt;
+
+#[test]
+fn test_eint_multiplication() {
+    let w = EInt::omega();
+    assert_eq!(w * w, EInt::new(-1, -1));
+    assert_eq!(w * w * w, EInt::one());
+    assert_eq!(EInt::new(2, 3) * EInt::new(1, -1), EInt::new(5, 4));
+}
+
+#[test]
+fn test_eint_norm() {
+    assert_eq!(EInt::new(2, 3).norm_squared(), 7);
+    assert_eq!(EInt::new(1, -1).norm_squared(), 3);
+    assert_eq!(EInt::omega().norm_squared(), 1);
+
+    let x = EInt::new(4, -7);
+    assert_eq!(x * x.conj(), EInt::new(x.norm_squared() as i32, 0));
+    assert_eq!((x * EInt::new(2, 3)).norm_squared(), x.norm_squared() * 7);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_eint_div_rem_and_gcd() {
+    let a = EInt::new(17, -5);
+    let d = EInt::new(3, 4);
+    let (q, r) = a.div_rem(d).unwrap();
+    assert_eq!(q * d + r, a);
+    assert!(r.norm_squared() < d.norm_squared());
+
+    // 3 = -ω²(1 - ω)², so gcd(3, 1 - ω) is 1 - ω up to a unit
+    let g = EInt::gcd(EInt::new(3, 0), EInt::new(1, -1));
+    assert_eq!(g, EInt::new(1, -1).normalize());
+    assert_eq!(g.norm_squared(), 3);
+
+    let common = EInt::new(2, 3);
+    let g = EInt::gcd(common * EInt::new(1, 2), common * EInt::new(3, 1));
+    assert_eq!(g, common.normalize());
+    assert!(EInt::new(7, 0).div_exact(g).is_ok());
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "std")]
+
+use entropy_hpc::{CInt, EntropyError, OInt};
+use entropy_hpc::types::cint::CIntError;
+use entropy_hpc::types::oint::OIntError;
+
+// Exact quotients in both rings, with either error propagated by `?`
+fn divide_both(c: (CInt, CInt), o: (OInt, OInt)) -> Result<(CInt, OInt), EntropyError> {
+    let cq = c.0.div_exact(c.1)?;
+    let oq = o.0.div_exact(o.1)?;
+    Ok((cq, oq))
+}
+
+#[test]
+fn test_question_mark_across_types() {
+    let c = (CInt::new(6, 8), CInt::new(3, 4));
+    let o = (OInt::new(4, 0, 2, 0, 0, 0, 0, 0), OInt::new(2, 0, 1, 0, 0, 0, 0, 0));
+    assert_eq!(divide_both(c, o), Ok((CInt::new(2, 0), OInt::new(2, 0, 0, 0, 0, 0, 0, 0))));
+
+    let bad_c = (CInt::one(), CInt::zero());
+    assert_eq!(divide_both(bad_c, o), Err(EntropyError::CInt(CIntError::DivisionByZero)));
+
+    let bad_o = (OInt::one(), OInt::zero());
+    assert_eq!(divide_both(c, bad_o), Err(EntropyError::OInt(OIntError::DivisionByZero)));
+    assert_eq!(EntropyError::from(OIntError::NoInverse), EntropyError::OInt(OIntError::NoInverse));
+}
+
+#[test]
+fn test_error_display_and_source() {
+    use entropy_hpc::types::hint::HIntError;
+    use std::error::Error;
+
+    assert_eq!(format!("{}", OIntError::NoInverse), "element has no inverse");
+    assert_eq!(CIntError::DivisionByZero.to_string(), "division by zero");
+    assert_eq!(HIntError::InvalidHalfInteger.to_string(), "components must be all integers or all half-integers");
+
+    let e = EntropyError::from(OIntError::NotInLattice);
+    assert_eq!(e.to_string(), "OInt: not a point of the E8 lattice");
+    assert_eq!(e.source().unwrap().to_string(), OIntError::NotInLattice.to_string());
+
+    // Usable as a boxed trait object
+    let boxed: Box<dyn Error> = Box::new(CIntError::NotDivisible);
+    assert_eq!(boxed.to_string(), "not exactly divisible");
+}
@@ -0,0 +1,340 @@
+use entropy_hpc::CInt;
+use entropy_hpc::types::cint::CIFraction;
+#[cfg(feature = "std")]
+use entropy_hpc::types::cint::CIntError;
+use std::collections::HashSet;
+
+#[test]
+fn test_fraction_hash_after_normalization() {
+    let half = CIFraction { num: CInt::new(1, 1), den: 2 };
+    let two_quarters = CIFraction { num: CInt::new(2, 2), den: 4 };
+    let three_sixths = CIFraction { num: CInt::new(3, 3), den: 6 };
+    assert_eq!(half, two_quarters);
+    assert_ne!(half, CIFraction { num: CInt::new(1, 1), den: 3 });
+
+    let set: HashSet<CIFraction> = [half, two_quarters, three_sixths].into_iter().collect();
+    assert_eq!(set.len(), 1);
+
+    let normalized = three_sixths.normalized();
+    assert_eq!((normalized.num, normalized.den), (CInt::new(1, 1), 2));
+}
+
+#[test]
+fn test_hurwitz_fraction_eq_is_on_reduced_form() {
+    use entropy_hpc::HInt;
+    use entropy_hpc::types::hint::HIFraction;
+
+    // ½(1,1,1,1) over 1 and (1,1,1,1) over 2 are the same value
+    let h = HInt::from_halves(1, 1, 1, 1).unwrap();
+    let x = HIFraction { num: h, den: 1 };
+    let y = HIFraction { num: HInt::new(1, 1, 1, 1), den: 2 };
+    assert_eq!(x, y);
+
+    let set: HashSet<HIFraction> = [x, y].into_iter().collect();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn test_octonion_fraction_eq_with_mixed_parity_numerator() {
+    use entropy_hpc::OInt;
+    use entropy_hpc::types::oint::OIFraction;
+
+    // ½(1 + e1 + e2 + e3) is integral but not all one parity
+    let u = OInt::unit(16).unwrap();
+    let fracs = [
+        OIFraction { num: u, den: 1 },
+        OIFraction { num: u.scale(2), den: 2 },
+        OIFraction { num: u.scale(6), den: 6 },
+    ];
+    assert_eq!(fracs[0], fracs[1]);
+    assert_eq!(fracs[1], fracs[2]);
+    assert_ne!(fracs[0], OIFraction { num: u, den: 2 });
+
+    let set: HashSet<OIFraction> = fracs.into_iter().collect();
+    assert_eq!(set.len(), 1);
+}
+
+// a/da == b/db  <=>  a*db == b*da, compared on the stored components
+fn same_value(a: &[i32], da: u64, b: &[i32], db: u64) -> bool {
+    a.iter().zip(b).all(|(&x, &y)| x as i64 * db as i64 == y as i64 * da as i64)
+}
+
+#[test]
+fn test_reduce_fraction_preserves_value() {
+    use entropy_hpc::{HInt, OInt};
+    use entropy_hpc::types::hint::HIFraction;
+    use entropy_hpc::types::oint::OIFraction;
+
+    let frac = OIFraction { num: OInt::new(2, 2, 0, 0, 0, 0, 0, 0), den: 4 };
+    let reduced = OInt::reduce_fraction(frac);
+    assert!(same_value(&frac.num.raw_components(), frac.den, &reduced.num.raw_components(), reduced.den));
+    assert_eq!(reduced.num, OInt::new(1, 1, 0, 0, 0, 0, 0, 0));
+    assert_eq!(reduced.den, 2);
+    let again = OInt::reduce_fraction(reduced);
+    assert_eq!((again.num, again.den), (reduced.num, reduced.den));
+
+    let frac = OIFraction { num: OInt::new(3, 6, 0, 9, 0, 0, 3, 0), den: 6 };
+    let reduced = OInt::reduce_fraction(frac);
+    assert!(same_value(&frac.num.raw_components(), frac.den, &reduced.num.raw_components(), reduced.den));
+    assert_eq!(reduced.den, 2);
+
+    let frac = HIFraction { num: HInt::new(4, 8, 0, 4), den: 12 };
+    let reduced = HInt::reduce_fraction(frac);
+    assert!(same_value(&frac.num.raw_components(), frac.den, &reduced.num.raw_components(), reduced.den));
+    assert_eq!(reduced.num, HInt::new(1, 2, 0, 1));
+    assert_eq!(reduced.den, 3);
+    let again = HInt::reduce_fraction(reduced);
+    assert_eq!((again.num, again.den), (reduced.num, reduced.den));
+}
+
+#[test]
+fn test_reduce_fraction_is_canonical() {
+    use entropy_hpc::{HInt, OInt};
+    use entropy_hpc::types::hint::HIFraction;
+    use entropy_hpc::types::oint::OIFraction;
+
+    // The same value scaled by k reduces to the identical struct
+    let nums = [CInt::new(-1, -1), CInt::new(3, -4), CInt::new(0, 5), CInt::zero()];
+    for num in nums {
+        for den in [1u64, 2, 6] {
+            let base = CInt::reduce_fraction(CIFraction { num, den });
+            for k in [2, 3, 10] {
+                let scaled = CIFraction { num: num.scale(k), den: den * k as u64 };
+                let reduced = CInt::reduce_fraction(scaled);
+                assert_eq!((reduced.num, reduced.den), (base.num, base.den));
+            }
+        }
+    }
+    assert_eq!(CInt::reduce_fraction(CIFraction { num: CInt::zero(), den: 9 }), CIFraction::default());
+
+    // (-1-i)/2 and (1+i)/2 are different values and stay distinct
+    let neg = CInt::reduce_fraction(CIFraction { num: CInt::new(-2, -2), den: 4 });
+    let pos = CInt::reduce_fraction(CIFraction { num: CInt::new(2, 2), den: 4 });
+    assert_eq!(neg.num, -pos.num);
+    assert_eq!(neg.den, pos.den);
+
+    let h = HInt::from_halves(1, -1, 1, 3).unwrap();
+    for den in [1u64, 2, 3] {
+        let base = HInt::reduce_fraction(HIFraction { num: h, den });
+        for k in [2, 4, 6] {
+            let scaled = HIFraction { num: h.scale(k), den: den * k as u64 };
+            let reduced = HInt::reduce_fraction(scaled);
+            assert_eq!((reduced.num, reduced.den), (base.num, base.den));
+        }
+    }
+
+    // Mixed-parity units are integral too: ½(1 + e1 + e2 + e3) over 1 is
+    // already in lowest terms
+    let u = OInt::unit(16).unwrap();
+    let doubled = OInt::reduce_fraction(OIFraction { num: u.scale(2), den: 2 });
+    assert_eq!((doubled.num, doubled.den), (u, 1));
+
+    for o in [OInt::from_halves(1, 1, 1, 1, -1, 1, 1, 1).unwrap(), u, OInt::unit(200).unwrap()] {
+        for den in [1u64, 2, 5] {
+            let base = OInt::reduce_fraction(OIFraction { num: o, den });
+            for k in [2, 4, 5] {
+                let scaled = OIFraction { num: o.scale(k), den: den * k as u64 };
+                let reduced = OInt::reduce_fraction(scaled);
+                assert_eq!((reduced.num, reduced.den), (base.num, base.den));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_continued_fraction_round_trip() {
+    let frac = CInt::new(3, 4).div_to_fraction(CInt::new(1, 2)).unwrap().normalized();
+    assert_eq!(frac, CIFraction { num: CInt::new(11, -2), den: 5 });
+
+    let quotients = frac.continued_fraction().unwrap();
+    assert!(!quotients.is_empty());
+    assert_eq!(CIFraction::from_continued_fraction(&quotients).unwrap(), frac);
+
+    for (num, den) in [((7, 0), 1), ((-13, 29), 17), ((100, -3), 64), ((0, 0), 9)] {
+        let frac = CIFraction { num: CInt::new(num.0, num.1), den }.normalized();
+        let quotients = frac.continued_fraction().unwrap();
+        assert_eq!(CIFraction::from_continued_fraction(&quotients).unwrap(), frac);
+    }
+
+    // An integer expands to itself
+    assert_eq!(CIFraction { num: CInt::new(5, -2), den: 1 }.continued_fraction().unwrap(), vec![CInt::new(5, -2)]);
+    assert_eq!(CIFraction::from_continued_fraction(&[]), Err(CIntError::InvalidFormat));
+    let wide = CIFraction { num: CInt::one(), den: i32::MAX as u64 + 1 };
+    assert_eq!(wide.continued_fraction(), Err(CIntError::Overflow));
+}
+
+#[test]
+fn test_fraction_arithmetic_reduces() {
+    use entropy_hpc::{HInt, OInt};
+    use entropy_hpc::types::hint::HIFraction;
+    use entropy_hpc::types::oint::OIFraction;
+
+    let half = CIFraction { num: CInt::new(1, 1), den: 2 };
+    let third = CIFraction { num: CInt::new(1, -1), den: 3 };
+    assert_eq!(half + third, CIFraction { num: CInt::new(5, 1), den: 6 });
+    assert_eq!(half - third, CIFraction { num: CInt::new(1, 5), den: 6 });
+
+    // (1+i)/2 + (1-i)/2 = 1 and (1+i)/2 * (1-i)/2 = 2/4 = 1/2, both in lowest terms
+    let conj_half = CIFraction { num: CInt::new(1, -1), den: 2 };
+    assert_eq!(half + conj_half, CIFraction { num: CInt::one(), den: 1 });
+    assert_eq!(half * conj_half, CIFraction { num: CInt::one(), den: 2 });
+    assert_eq!(half - half, CIFraction { num: CInt::zero(), den: 1 });
+
+    // Quaternion fractions multiply in the order written: (i/2)(j/3) = k/6 = -(j/3)(i/2)
+    let i_half = HIFraction { num: HInt::i(), den: 2 };
+    let j_third = HIFraction { num: HInt::j(), den: 3 };
+    assert_eq!(i_half * j_third, HIFraction { num: HInt::k(), den: 6 });
+    assert_eq!(j_third * i_half, HIFraction { num: -HInt::k(), den: 6 });
+    assert_eq!(i_half + i_half, HIFraction { num: HInt::i(), den: 1 });
+
+    let e1_half = OIFraction { num: OInt::e1(), den: 2 };
+    let e2_half = OIFraction { num: OInt::e2(), den: 2 };
+    assert_eq!(e1_half * e2_half, OIFraction { num: OInt::e3(), den: 4 });
+    assert_eq!(e2_half * e1_half, OIFraction { num: -OInt::e3(), den: 4 });
+    assert_eq!(e1_half - e1_half + e2_half, e2_half);
+}
+
+#[test]
+#[should_panic(expected = "HIFraction denominator overflow")]
+fn test_hurwitz_common_den_overflow_panics() {
+    use entropy_hpc::HInt;
+    use entropy_hpc::types::hint::HIFraction;
+
+    // Scaling by 2^30 fits i32, but the stored 2 * 2^30 does not
+    let _ = HIFraction::from(HInt::one()) + HIFraction { num: HInt::one(), den: 1 << 30 };
+}
+
+#[test]
+#[should_panic(expected = "OIFraction denominator overflow")]
+fn test_octonion_common_den_overflow_panics() {
+    use entropy_hpc::OInt;
+    use entropy_hpc::types::oint::OIFraction;
+
+    let _ = OIFraction::from(OInt::one()) - OIFraction { num: OInt::one(), den: i32::MAX as u64 };
+}
+
+#[test]
+fn test_div_operator() {
+    use entropy_hpc::{HInt, OInt};
+    use entropy_hpc::types::hint::HIFraction;
+    use entropy_hpc::types::oint::OIFraction;
+
+    assert_eq!((CInt::new(6, 0) / CInt::new(2, 0)).normalized(), CIFraction::from(CInt::new(3, 0)));
+    assert_eq!((CInt::new(3, 4) / CInt::new(1, 2)).normalized(), CIFraction { num: CInt::new(11, -2), den: 5 });
+
+    // Right division: (i / j) * j = i, while the left quotient j⁻¹ * i differs
+    let right = HInt::i() / HInt::j();
+    assert_eq!(right.normalized(), HIFraction::from(-HInt::k()));
+    assert_eq!((right * HIFraction::from(HInt::j())).normalized(), HIFraction::from(HInt::i()));
+    let left = HInt::j().inv_fraction().unwrap() * HIFraction::from(HInt::i());
+    assert_eq!(left.normalized(), HIFraction::from(HInt::k()));
+
+    let a = HInt::new(3, -1, 2, 5);
+    let b = HInt::new(1, 1, 0, 2);
+    assert_eq!(((a / b) * HIFraction::from(b)).normalized(), HIFraction::from(a));
+
+    let x = OInt::new(2, 0, -1, 3, 0, 1, 1, 4);
+    let y = OInt::new(1, 0, 2, 0, -1, 0, 0, 1);
+    assert_eq!((OInt::e3() / OInt::e2()).normalized(), OIFraction::from(OInt::e1()));
+    assert_eq!(((x / y) * OIFraction::from(y)).normalized(), OIFraction::from(x));
+}
+
+#[test]
+#[should_panic(expected = "CInt division by zero")]
+fn test_div_by_zero_panics() {
+    let _ = CInt::one() / CInt::zero();
+}
+
+#[test]
+fn test_pow_frac() {
+    use entropy_hpc::{HInt, OInt};
+
+    let z = CInt::new(2, 1);
+    assert_eq!(z.pow(3), z * z * z);
+    assert_eq!(z.pow(0), CInt::one());
+    assert_eq!(z.pow_frac(2).unwrap(), CIFraction::from(z.pow(2)));
+    assert_eq!(z.pow_frac(0).unwrap(), CIFraction::from(CInt::one()));
+    assert_eq!(z.pow_frac(-1).unwrap(), CInt::reduce_fraction(z.inv_fraction().unwrap()));
+    // z^-2 * z^2 = 1
+    assert_eq!(z.pow_frac(-2).unwrap() * z.pow_frac(2).unwrap(), CIFraction::from(CInt::one()));
+    assert!(CInt::zero().pow_frac(-1).is_err());
+
+    let h = HInt::from_halves(1, 1, 1, -1).unwrap();
+    assert_eq!(h.pow(3), -HInt::one());
+    assert_eq!(h.pow_frac(-1).unwrap(), HInt::reduce_fraction(h.inv_fraction().unwrap()));
+
+    let o = OInt::new(1, 1, 0, 0, 1, 0, 0, 0);
+    assert_eq!(o.pow(4), (o * o) * (o * o));
+    assert_eq!(o.pow_frac(2).unwrap(), OInt::reduce_fraction(o.pow(2).into()));
+    assert_eq!(o.pow_frac(-1).unwrap(), OInt::reduce_fraction(o.inv_fraction().unwrap()));
+    assert_eq!(o.pow_frac(-3).unwrap() * o.pow_frac(3).unwrap(), OInt::one().into());
+}
+
+#[test]
+fn test_fraction_norm_squared() {
+    use entropy_hpc::{HInt, OInt};
+
+    let z = CInt::new(3, 4);
+    assert_eq!(z.inv_fraction().unwrap().norm_squared(), (1, 25));
+    let half = CInt::new(3, 1).div_to_fraction(CInt::new(1, 1)).unwrap();
+    assert_eq!(half.norm_squared(), (5, 1));
+    assert_eq!(CIFraction::from(CInt::zero()).norm_squared(), (0, 1));
+
+    let h = HInt::new(1, 1, 1, 0);
+    assert_eq!(h.inv_fraction().unwrap().norm_squared(), (1, h.norm_squared()));
+    let h_half = HInt::from_halves(1, 1, 1, 1).unwrap().div_to_fraction(HInt::new(2, 0, 0, 0)).unwrap();
+    assert_eq!(h_half.norm_squared(), (1, 4));
+
+    let o = OInt::new(1, 2, 0, 0, 0, 0, 1, 0);
+    assert_eq!(o.inv_fraction().unwrap().norm_squared(), (1, 6));
+    assert_eq!(o.pow_frac(-2).unwrap().norm_squared(), (1, 36));
+}
+
+#[test]
+fn test_to_rational_parts() {
+    let frac = CIFraction { num: CInt::new(3, 6), den: 9 };
+    assert_eq!(frac.to_rational_parts(), ((1, 3), (2, 3)));
+
+    // Each part reduces on its own
+    let frac = CIFraction { num: CInt::new(-4, 3), den: 6 };
+    assert_eq!(frac.to_rational_parts(), ((-2, 3), (1, 2)));
+
+    // A zero part becomes 0/1
+    let frac = CIFraction { num: CInt::new(5, 0), den: 10 };
+    assert_eq!(frac.to_rational_parts(), ((1, 2), (0, 1)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_best_approximation() {
+    let frac = CIFraction { num: CInt::new(12_345, -7_654), den: 99_991 }.normalized();
+    let dist2 = |c: CIFraction| {
+        let re = frac.num.a as f64 / frac.den as f64 - c.num.a as f64 / c.den as f64;
+        let im = frac.num.b as f64 / frac.den as f64 - c.num.b as f64 / c.den as f64;
+        re * re + im * im
+    };
+
+    let quotients = frac.continued_fraction().unwrap();
+    let convergents: Vec<CIFraction> = (1..=quotients.len())
+        .map(|n| CIFraction::from_continued_fraction(&quotients[..n]).unwrap())
+        .collect();
+
+    for bound in [1, 10, 100, 1000, 10_000] {
+        let approx = frac.best_approximation(bound).unwrap();
+        assert!(approx.den <= bound);
+        assert!(convergents.contains(&approx));
+        for &c in convergents.iter().filter(|c| c.den <= bound) {
+            assert!(dist2(approx) <= dist2(c));
+        }
+    }
+
+    // A bound at or above the denominator returns the fraction itself
+    assert_eq!(frac.best_approximation(frac.den), Ok(frac));
+    assert_eq!(frac.best_approximation(0), frac.best_approximation(1));
+    assert_eq!(frac.best_approximation(1).unwrap().den, 1);
+
+    // A denominator past i32 cannot seed the Euclidean algorithm
+    let wide = CIFraction { num: CInt::one(), den: 1 << 31 };
+    assert_eq!(wide.best_approximation(10), Err(CIntError::Overflow));
+}
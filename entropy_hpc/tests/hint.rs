@@ -0,0 +1,129 @@
+use entropy_hpc::HInt;
+#[cfg(feature = "std")]
+use entropy_hpc::types::hint::HIntError;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+#[cfg(feature = "std")]
+#[test]
+fn test_hurwitz_vs_lipschitz_division() {
+    // i + j = ((1 + i + j + k)/2) * (1 + i): the exact quotient is a half-integer unit
+    let a = HInt::new(0, 1, 1, 0);
+    let d = HInt::new(1, 1, 0, 0);
+
+    let (q, r) = a.div_rem(d).unwrap();
+    assert!(r.is_zero());
+    assert_eq!(q, HInt::from_halves(1, 1, 1, 1).unwrap());
+    assert!(!q.is_lipschitz());
+
+    let (q_l, r_l) = a.div_rem_lipschitz(d).unwrap();
+    assert!(q_l.is_lipschitz());
+    assert_eq!(q_l * d + r_l, a);
+    assert!(r_l.norm_squared() >= d.norm_squared());
+    assert!(r_l.norm_squared() > r.norm_squared());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_hurwitz_vs_lipschitz_gcd() {
+    let a = HInt::new(0, 1, 1, 0);
+    let d = HInt::new(1, 1, 0, 0);
+
+    assert_eq!(HInt::gcd(a, d), d);
+    assert_eq!(HInt::gcd_lipschitz(a, d), None);
+
+    // Both agree when the Lipschitz algorithm makes progress
+    assert_eq!(HInt::gcd_lipschitz(HInt::new(4, 0, 0, 0), HInt::new(2, 0, 0, 0)), Some(HInt::new(2, 0, 0, 0)));
+}
+
+#[test]
+fn test_raw_round_trip() {
+    let h = HInt::from_halves(1, -1, 3, 1).unwrap();
+    let raw = h.raw_components();
+    assert_eq!(raw, [1, -1, 3, 1]);
+    assert_eq!(HInt::from_raw(raw[0], raw[1], raw[2], raw[3]), h);
+
+    assert_eq!(HInt::new(1, 2, 3, 4).raw_components(), [2, 4, 6, 8]);
+
+    // Mixed parity is representable through from_raw, unlike from_halves
+    let mixed = HInt::from_raw(1, 2, 0, 0);
+    assert!(HInt::from_halves(1, 2, 0, 0).is_err());
+    assert_eq!(mixed.raw_components(), [1, 2, 0, 0]);
+}
+
+#[test]
+fn test_commutator() {
+    assert_eq!(HInt::commutator(HInt::i(), HInt::j()), HInt::new(0, 0, 0, 2));
+    assert!(HInt::commutator(HInt::new(5, 0, 0, 0), HInt::new(1, 2, 3, 4)).is_zero());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_div_rem_int_matches_float_path() {
+    let mut rng = ChaCha8Rng::seed_from_u64(24);
+    for _ in 0..1000 {
+        let a = HInt::new(rng.gen_range(-500..500), rng.gen_range(-500..500), rng.gen_range(-500..500), rng.gen_range(-500..500));
+        let d = HInt::new(rng.gen_range(-30..30), rng.gen_range(-30..30), rng.gen_range(-30..30), rng.gen_range(1..30));
+        assert_eq!(a.div_rem_int(d).unwrap(), a.div_rem(d).unwrap());
+    }
+    assert_eq!(HInt::one().div_rem_int(HInt::zero()), Err(HIntError::DivisionByZero));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_div_rem_int_large_numerator() {
+    // a * conj(d) has real part 1.5e9, stored as 3e9: beyond i32, so both
+    // paths must keep the numerator wide rather than narrow it through Mul
+    let a = HInt::new(50_000_000, 0, 0, 0);
+    let d = HInt::new(30, 10, 0, 0);
+
+    let (q, r) = a.div_rem_int(d).unwrap();
+    assert_eq!(q, HInt::new(1_500_000, -500_000, 0, 0));
+    assert!(r.is_zero());
+
+    assert_eq!(a.div_rem(d).unwrap(), (q, r));
+}
+
+#[test]
+fn test_norm_multiplicative() {
+    let mut rng = ChaCha8Rng::seed_from_u64(31);
+    for _ in 0..1000 {
+        let parity = rng.gen_range(0..2);
+        let mut c = [0i32; 4];
+        for x in c.iter_mut() {
+            *x = rng.gen_range(-50..50) * 2 + parity;
+        }
+        let a = HInt::from_halves(c[0], c[1], c[2], c[3]).unwrap();
+        let b = HInt::new(rng.gen_range(-50..50), rng.gen_range(-50..50), rng.gen_range(-50..50), rng.gen_range(-50..50));
+        assert!(HInt::check_norm_multiplicative(a, b));
+        assert!(HInt::check_norm_multiplicative(b, a));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_div_rem_remainder_is_minimal() {
+    let mut rng = ChaCha8Rng::seed_from_u64(26);
+    for _ in 0..5000 {
+        let parity = rng.gen_range(0..2);
+        let mut c = [0i32; 4];
+        for x in c.iter_mut() {
+            *x = rng.gen_range(-400..400) * 2 + parity;
+        }
+        let a = HInt::from_halves(c[0], c[1], c[2], c[3]).unwrap();
+
+        let parity = rng.gen_range(0..2);
+        for x in c.iter_mut() {
+            *x = rng.gen_range(-10..10) * 2 + parity;
+        }
+        let d = HInt::from_halves(c[0], c[1], c[2], c[3]).unwrap();
+        if d.is_zero() {
+            continue;
+        }
+
+        let (q, r) = a.div_rem(d).unwrap();
+        assert_eq!(q * d + r, a);
+        assert!(r.norm_squared() < d.norm_squared());
+        assert!(2 * r.norm_squared() <= d.norm_squared());
+    }
+}
@@ -0,0 +1,438 @@
+use entropy_hpc::{CInt, EInt, HInt, OInt};
+
+#[test]
+fn test_checked_lattice_distance_squared() {
+    let o1 = OInt::new(1, 1, 1, 1, 0, 0, 0, 0);
+    let o2 = OInt::new(2, 0, 0, 0, 2, 0, 0, 0);
+    assert_eq!(o1.checked_lattice_distance_squared(o2), Some(o1.lattice_distance_squared(o2) as i64));
+
+    let h1 = HInt::new(1, 2, 3, 4);
+    let h2 = HInt::new(-1, 0, 5, 4);
+    assert_eq!(h1.checked_lattice_distance_squared(h2), Some(h1.lattice_distance_squared(h2) as i64));
+
+    let z1 = CInt::new(3, 4);
+    let z2 = CInt::new(1, 2);
+    assert_eq!(z1.checked_lattice_distance_squared(z2), Some(z1.lattice_distance_squared(z2) as i64));
+}
+
+#[test]
+fn test_checked_lattice_distance_squared_overflow() {
+    let max = OInt { a: i32::MAX, b: i32::MAX, c: i32::MAX, d: i32::MAX,
+                     e: i32::MAX, f: i32::MAX, g: i32::MAX, h: i32::MAX };
+    let min = -max;
+    assert_eq!(max.checked_lattice_distance_squared(min), None);
+
+    let big = OInt { a: i32::MAX, ..OInt::zero() };
+    assert!(big.checked_lattice_distance_squared(OInt::zero()).is_some());
+
+    let hmax = HInt { a: i32::MAX, b: i32::MAX, c: i32::MAX, d: i32::MAX };
+    assert_eq!(hmax.checked_lattice_distance_squared(-hmax), None);
+
+    let cmax = CInt::new(i32::MAX, i32::MAX);
+    assert_eq!(cmax.checked_lattice_distance_squared(CInt::new(i32::MIN, i32::MIN)), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_quantization_error_within_covering_radius() {
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    let mut rng = ChaCha8Rng::seed_from_u64(34);
+    for _ in 0..2000 {
+        let t8: [f64; 8] = std::array::from_fn(|_| rng.gen_range(-10.0..10.0));
+        let p = OInt::closest_lattice_point(t8);
+        assert!(OInt::is_in_lattice(p.to_lattice_vector()));
+        assert!(OInt::quantization_error_squared(t8) <= 1.0 + 1e-9);
+
+        let t4: [f64; 4] = std::array::from_fn(|_| rng.gen_range(-10.0..10.0));
+        let p = HInt::closest_lattice_point(t4);
+        assert!(HInt::is_in_lattice(p.to_lattice_vector()));
+        assert!(HInt::quantization_error_squared(t4) <= 1.0 + 1e-9);
+
+        let t2 = (rng.gen_range(-10.0..10.0), rng.gen_range(-10.0..10.0));
+        assert!(CInt::quantization_error_squared(t2) <= 0.5 + 1e-9);
+    }
+
+    // A deep hole of E₈ sits exactly at the covering radius
+    let hole = [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+    assert!((OInt::quantization_error_squared(hole) - 1.0).abs() < 1e-12);
+
+    // Lattice points quantize to themselves
+    let on = [0.5, 0.5, -0.5, 0.5, 0.5, 0.5, 0.5, -0.5];
+    assert_eq!(OInt::quantization_error_squared(on), 0.0);
+    assert_eq!(OInt::closest_lattice_point(on), OInt::from_halves(1, 1, -1, 1, 1, 1, 1, -1).unwrap());
+}
+
+// Squared distance from a real target to a lattice point in doubled coordinates
+#[cfg(feature = "std")]
+fn dist2_doubled(target: &[f64], raw: &[i32]) -> f64 {
+    target.iter().zip(raw).map(|(&t, &x)| (t - x as f64 / 2.0).powi(2)).sum()
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_closest_lattice_point_matches_brute_force() {
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    // Targets with |t| <= 1 have their nearest E₈ point (covering radius 1)
+    // within norm 4, so vectors_within(4) is an exhaustive candidate set
+    let e8_candidates = OInt::vectors_within(4);
+
+    // Every D₄ point with stored coordinates in -6..=6, which covers the
+    // nearest point of any target in [-1, 1]⁴
+    let mut d4_candidates = Vec::new();
+    for a in -6..=6 {
+        for b in -6..=6 {
+            for c in -6..=6 {
+                for d in -6..=6 {
+                    if HInt::is_in_lattice((a, b, c, d)) {
+                        d4_candidates.push([a, b, c, d]);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut rng = ChaCha8Rng::seed_from_u64(35);
+    for _ in 0..300 {
+        let t8: [f64; 8] = std::array::from_fn(|_| rng.gen_range(-0.35..0.35));
+        let best = e8_candidates.iter()
+            .map(|p| dist2_doubled(&t8, &p.raw_components()))
+            .fold(f64::INFINITY, f64::min);
+        let got = dist2_doubled(&t8, &OInt::closest_lattice_point(t8).raw_components());
+        assert!((got - best).abs() < 1e-9, "E8 target {:?}: {} vs {}", t8, got, best);
+
+        let t4: [f64; 4] = std::array::from_fn(|_| rng.gen_range(-1.0..1.0));
+        let best = d4_candidates.iter()
+            .map(|p| dist2_doubled(&t4, p))
+            .fold(f64::INFINITY, f64::min);
+        let got = dist2_doubled(&t4, &HInt::closest_lattice_point(t4).raw_components());
+        assert!((got - best).abs() < 1e-9, "D4 target {:?}: {} vs {}", t4, got, best);
+
+        let t2 = (rng.gen_range(-2.0..2.0), rng.gen_range(-2.0..2.0));
+        let dist2 = |p: CInt| (t2.0 - p.a as f64).powi(2) + (t2.1 - p.b as f64).powi(2);
+        let best = (-3..=3)
+            .flat_map(|a| (-3..=3).map(move |b| CInt::new(a, b)))
+            .map(dist2)
+            .fold(f64::INFINITY, f64::min);
+        assert!((dist2(CInt::closest_lattice_point(t2)) - best).abs() < 1e-12);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_filter_in_lattice() {
+    use entropy_hpc::simd::LatticeSimd;
+
+    let e8_points = [
+        (2, 2, 0, 0, 0, 0, 0, 0),        // integer, coordinate sum 2
+        (1, 1, 1, 1, 1, 1, 1, 1),        // half-integer, sum 4
+        (2, 0, 0, 0, 0, 0, 0, 0),        // odd sum
+        (1, 1, 1, 1, 1, 1, 1, -1),       // half-integer, odd sum
+        (1, 2, 0, 0, 0, 0, 0, 1),        // mixed parity
+        (1, -1, 1, -1, 1, 1, -1, -1),    // half-integer, sum 0
+    ];
+    let filtered = LatticeSimd::e8_filter_in_lattice(&e8_points);
+    assert_eq!(filtered, vec![
+        OInt::new(1, 1, 0, 0, 0, 0, 0, 0),
+        OInt::from_halves(1, 1, 1, 1, 1, 1, 1, 1).unwrap(),
+        OInt::from_halves(1, -1, 1, -1, 1, 1, -1, -1).unwrap(),
+    ]);
+    assert!(filtered.iter().all(|p| OInt::is_in_lattice(p.to_lattice_vector())));
+
+    let d4_points = [(2, 2, 0, 0), (1, 1, 1, 1), (2, 0, 0, 0), (1, 0, 1, 0)];
+    assert_eq!(LatticeSimd::d4_filter_in_lattice(&d4_points), vec![
+        HInt::new(1, 1, 0, 0),
+        HInt::from_halves(1, 1, 1, 1).unwrap(),
+    ]);
+
+    let z2_points = [(3, -4), (0, 0)];
+    assert_eq!(LatticeSimd::z2_filter_in_lattice(&z2_points), vec![CInt::new(3, -4), CInt::zero()]);
+}
+
+#[cfg(feature = "std")]
+fn check_generic_batch<L: entropy_hpc::lattice::Lattice + PartialEq + std::fmt::Debug>(points: &[L], norms: &[i32]) {
+    use entropy_hpc::simd::LatticeSimd;
+
+    assert_eq!(LatticeSimd::norm_squared_batch(points), norms);
+    assert_eq!(LatticeSimd::distance_squared_batch(points, points[0])[0], 0);
+
+    let vectors: Vec<L::Point> = points.iter().map(|&p| p.to_vector()).collect();
+    assert!(LatticeSimd::in_lattice_batch::<L>(&vectors).iter().all(|&m| m));
+    for (&p, &v) in points.iter().zip(&vectors) {
+        assert_eq!(L::from_vector(v), p);
+        assert_eq!(L::closest(v), p);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_lattice_trait_batches() {
+    check_generic_batch(&[CInt::new(3, 4), CInt::new(-1, 0), CInt::zero()], &[25, 1, 0]);
+    check_generic_batch(
+        &[HInt::new(1, 1, 0, 0), HInt::from_halves(1, 1, -1, -1).unwrap(), HInt::new(2, 0, 0, 0)],
+        &[2, 1, 4],
+    );
+    check_generic_batch(
+        &[OInt::new(1, -1, 0, 0, 0, 0, 0, 0), OInt::from_halves(1, 1, 1, 1, 1, 1, 1, 1).unwrap(), OInt::new(0, 0, 2, 0, 0, 0, 1, 1)],
+        &[2, 2, 6],
+    );
+
+    // closest rounds an off-lattice doubled vector onto the lattice
+    use entropy_hpc::lattice::Lattice;
+    assert!(OInt::is_member(OInt::closest((3, 0, 0, 0, 0, 0, 0, 0)).to_vector()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_lattice_dot_and_gram_matrix() {
+    use entropy_hpc::simd::LatticeSimd;
+
+    let c = CInt::new(3, -4);
+    assert_eq!(c.lattice_dot(c), c.lattice_norm_squared());
+    assert_eq!(c.lattice_dot(CInt::new(4, 3)), 0);
+    let h = HInt::from_halves(1, 1, -1, 1).unwrap();
+    assert_eq!(h.lattice_dot(h), (h.lattice_norm_squared() as i64, 1));
+    assert_eq!(h.lattice_dot(HInt::new(1, 1, 0, 0)), (1, 1));
+    // Half-integer pairs need not have an integral dot
+    let p = HInt::from_halves(1, 1, 1, 1).unwrap();
+    let q = HInt::from_halves(1, 1, 1, -1).unwrap();
+    assert_eq!(p.lattice_dot(q), (1, 2));
+    assert_eq!(p.lattice_dot(-q), (-1, 2));
+    assert_eq!(HInt::bilinear_form(p, q), 1);
+    let o = OInt::from_halves(1, -1, 1, 1, 3, -1, 1, -1).unwrap();
+    assert_eq!(o.lattice_dot(o), (o.lattice_norm_squared() as i64, 1));
+    let u = OInt::from_raw(1, 1, 1, 1, 0, 0, 0, 0);
+    let v = OInt::from_raw(1, 1, 0, 0, 1, 1, 0, 0);
+    assert_eq!(u.lattice_dot(v), (1, 2));
+
+    // Bourbaki simple roots of E₈: ½(1,-1,-1,-1,-1,-1,-1,1), e1 + e2 and
+    // e_{k+1} - e_k; their Gram matrix is the E₈ Cartan matrix
+    let roots = [
+        OInt::from_halves(1, -1, -1, -1, -1, -1, -1, 1).unwrap(),
+        OInt::new(1, 1, 0, 0, 0, 0, 0, 0),
+        OInt::new(-1, 1, 0, 0, 0, 0, 0, 0),
+        OInt::new(0, -1, 1, 0, 0, 0, 0, 0),
+        OInt::new(0, 0, -1, 1, 0, 0, 0, 0),
+        OInt::new(0, 0, 0, -1, 1, 0, 0, 0),
+        OInt::new(0, 0, 0, 0, -1, 1, 0, 0),
+        OInt::new(0, 0, 0, 0, 0, -1, 1, 0),
+    ];
+    let edges = [(0, 2), (1, 3), (2, 3), (3, 4), (4, 5), (5, 6), (6, 7)];
+    let mut cartan = vec![vec![(0, 1); 8]; 8];
+    for (i, row) in cartan.iter_mut().enumerate() {
+        row[i] = (2, 1);
+    }
+    for &(i, j) in &edges {
+        cartan[i][j] = (-1, 1);
+        cartan[j][i] = (-1, 1);
+    }
+    assert_eq!(LatticeSimd::e8_gram_matrix(&roots), cartan);
+
+    let d4_basis = [HInt::new(1, 1, 0, 0), HInt::from_halves(1, 1, 1, 1).unwrap()];
+    assert_eq!(LatticeSimd::d4_gram_matrix(&d4_basis), vec![vec![(2, 1), (1, 1)], vec![(1, 1), (1, 1)]]);
+    assert_eq!(LatticeSimd::z2_gram_matrix(&[CInt::one(), CInt::i()]), vec![vec![1, 0], vec![0, 1]]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_vectors_within() {
+    assert_eq!(OInt::vectors_within(1), vec![OInt::zero()]);
+    assert!(OInt::vectors_within(-1).is_empty());
+
+    // Origin plus the 240 roots (the E₈ kissing number)
+    let shell = OInt::vectors_within(2);
+    assert_eq!(shell.len(), 241);
+    assert_eq!(shell[0], OInt::zero());
+    assert!(shell[1..].iter().all(|p| p.lattice_norm_squared() == 2));
+
+    // Theta series of E₈: 1 + 240q + 2160q² + ...
+    let ball = OInt::vectors_within(4);
+    assert_eq!(ball.len(), 1 + 240 + 2160);
+    assert!(ball.iter().all(|p| p.lattice_norm_squared() <= 4 && OInt::is_in_lattice(p.to_lattice_vector())));
+    assert!(ball.windows(2).all(|w| w[0].lattice_norm_squared() <= w[1].lattice_norm_squared()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_vectors_within_canonical_order() {
+    let shell = OInt::vectors_within(2);
+    assert_eq!(shell[..4], [
+        OInt::zero(),
+        OInt::from_raw(-2, -2, 0, 0, 0, 0, 0, 0),
+        OInt::from_raw(-2, 0, -2, 0, 0, 0, 0, 0),
+        OInt::from_raw(-2, 0, 0, -2, 0, 0, 0, 0),
+    ]);
+    assert_eq!(shell[240], OInt::from_raw(2, 2, 0, 0, 0, 0, 0, 0));
+
+    // Strictly increasing in (norm, stored components), so no ties
+    let key = |p: &OInt| (p.lattice_norm_squared(), p.raw_components());
+    assert!(OInt::vectors_within(4).windows(2).all(|w| key(&w[0]) < key(&w[1])));
+}
+
+#[test]
+fn test_a2_hexagonal_lattice() {
+    use entropy_hpc::EInt;
+
+    // Kissing number 6: the units ±1, ±ω, ±ω²
+    let mut shell = Vec::new();
+    for a in -3..=3 {
+        for b in -3..=3 {
+            let p = EInt::from_lattice_vector((a, b));
+            if p.lattice_norm_squared() == 1 {
+                shell.push(p);
+            }
+        }
+    }
+    assert_eq!(shell.len(), 6);
+    assert!(shell.iter().all(|p| p.is_unit()));
+
+    let ((a1, b1), (a2, b2)) = EInt::fundamental_domain();
+    assert_eq!(EInt::new(a1, b1).lattice_norm_squared(), 1);
+    assert_eq!(EInt::new(a2, b2).lattice_norm_squared(), 1);
+    // Unit basis vectors 60° apart span a cell of area sin 60° = √3/2
+    assert_eq!(EInt::new(a1, b1).lattice_distance_squared(EInt::new(a2, b2)), 1);
+    assert_eq!(EInt::lattice_volume(), (1, 2));
+
+    let x = EInt::new(4, -7);
+    assert_eq!(x.lattice_norm_squared() as u64, x.norm_squared());
+    assert_eq!(x.lattice_distance_squared(EInt::new(3, -8)), 1);
+    assert!(EInt::is_in_lattice(x.to_lattice_vector()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_e8_coset() {
+    use entropy_hpc::lattice::E8Coset;
+    use entropy_hpc::simd::LatticeSimd;
+
+    let integer = OInt::new(1, 1, 0, 0, 0, 0, 0, 0);
+    let half = OInt::from_halves(1, -1, 1, 1, 1, 1, -1, 1).unwrap();
+    let odd_sum = OInt::new(1, 0, 0, 0, 0, 0, 0, 0);
+    let mixed = OInt::from_raw(1, 2, 0, 0, 0, 0, 0, 1);
+
+    assert_eq!(integer.e8_coset(), Some(E8Coset::Integer));
+    assert_eq!(half.e8_coset(), Some(E8Coset::HalfInteger));
+    assert_eq!(odd_sum.e8_coset(), None);
+    assert_eq!(mixed.e8_coset(), None);
+    assert_eq!(OInt::zero().e8_coset(), Some(E8Coset::Integer));
+
+    assert_eq!(
+        LatticeSimd::e8_coset_batch(&[integer, half, odd_sum]),
+        vec![Some(E8Coset::Integer), Some(E8Coset::HalfInteger), None],
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_e8_adjacency_edges() {
+    use entropy_hpc::simd::LatticeSimd;
+
+    let cloud = [
+        OInt::zero(),
+        OInt::new(1, 1, 0, 0, 0, 0, 0, 0),
+        OInt::new(1, -1, 0, 0, 0, 0, 0, 0),
+        OInt::new(2, 2, 0, 0, 0, 0, 0, 0),
+        OInt::from_halves(1, 1, 1, 1, 1, 1, 1, 1).unwrap(),
+    ];
+    // The two integer roots and the half-integer one neighbour the origin;
+    // 2·r₁ is a root step from r₁ only, and r₁ - p₄ is itself a root
+    assert_eq!(LatticeSimd::e8_adjacency_edges(&cloud), vec![(0, 1), (0, 2), (0, 4), (1, 3), (1, 4)]);
+
+    assert!(LatticeSimd::e8_adjacency_edges(&cloud[..1]).is_empty());
+    assert!(LatticeSimd::e8_adjacency_edges(&[]).is_empty());
+}
+
+#[test]
+fn test_lattice_norm_squared_exact() {
+    // Half-integer Hurwitz and E₈ values have integral norms
+    let h = HInt::from_halves(1, -3, 5, 1).unwrap();
+    assert_eq!(h.lattice_norm_squared_exact(), (9, 1));
+    assert_eq!(h.lattice_norm_squared_exact().0, h.lattice_norm_squared() as u64);
+    let o = OInt::from_halves(1, 1, 1, 1, 1, 1, 1, -3).unwrap();
+    assert_eq!(o.lattice_norm_squared_exact(), (4, 1));
+
+    // Mixed parity: 1/4 + 1 = 5/4, which the integer version truncates to 1
+    let raw = HInt::from_raw(1, 2, 0, 0);
+    assert_eq!(raw.lattice_norm_squared_exact(), (5, 4));
+    assert_eq!(raw.lattice_norm_squared(), 1);
+    assert_eq!(OInt::from_raw(1, 1, 0, 0, 0, 0, 0, 0).lattice_norm_squared_exact(), (1, 2));
+
+    // No i32 overflow where the integer version would
+    let big = OInt::from_raw(i32::MIN, 0, 0, 0, 0, 0, 0, i32::MIN);
+    assert_eq!(big.lattice_norm_squared_exact(), (1 << 61, 1));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_z2_content_and_primitive_part() {
+    use entropy_hpc::simd::LatticeSimd;
+
+    let f = CInt::new(1, 1);
+    let points: Vec<CInt> = [CInt::new(3, 0), CInt::new(2, -1), CInt::new(0, 5), CInt::zero()]
+        .iter()
+        .map(|&p| f * p)
+        .collect();
+    assert_eq!(LatticeSimd::z2_content(&points), f.normalize());
+
+    let primitive = LatticeSimd::z2_primitive_part(&points);
+    assert!(LatticeSimd::z2_content(&primitive).is_unit());
+    assert!(primitive.iter().zip(&points).all(|(&p, &q)| p * f.normalize() == q));
+
+    assert_eq!(LatticeSimd::z2_content(&[]), CInt::zero());
+    assert_eq!(LatticeSimd::z2_primitive_part(&[CInt::zero()]), vec![CInt::zero()]);
+}
+
+#[test]
+fn test_lattice_norm_squared_u64() {
+    // 40000² + 40000² = 3.2e9 is past i32::MAX
+    let z = CInt::new(40000, 40000);
+    assert_eq!(z.lattice_norm_squared_u64(), 3_200_000_000);
+    assert_eq!(EInt::new(40000, -40000).lattice_norm_squared_u64(), 4_800_000_000);
+
+    // Doubled storage: 40000 is stored as 80000, whose square alone overflows
+    let h = HInt::new(40000, 0, 0, 0);
+    assert_eq!(h.lattice_norm_squared_u64(), 1_600_000_000);
+    assert_eq!(h.checked_lattice_distance_squared(HInt::zero()), Some(1_600_000_000));
+
+    let o = OInt::new(40000, 40000, 0, 0, 0, 0, 0, 0);
+    assert_eq!(o.lattice_norm_squared_u64(), 3_200_000_000);
+
+    let small = OInt::from_halves(1, 1, 1, 1, 1, 1, 1, 1).unwrap();
+    assert_eq!(small.lattice_norm_squared_u64(), small.lattice_norm_squared() as u64);
+}
+
+#[test]
+fn test_is_lattice_member() {
+    assert!(CInt::new(-3, 7).is_lattice_member());
+    assert!(EInt::new(5, -2).is_lattice_member());
+
+    // Members need an even sum of actual coordinates
+    assert!(HInt::new(1, 1, 0, 0).is_lattice_member());
+    assert!(!HInt::new(1, 0, 0, 0).is_lattice_member());
+    assert!(HInt::from_halves(1, 1, 1, 1).unwrap().is_lattice_member());
+    assert!(!HInt::from_halves(1, 1, 1, -1).unwrap().is_lattice_member());
+    assert!(!HInt::from_raw(1, 2, 0, 1).is_lattice_member());
+    // The even-sum model, not the ring: units with an odd sum are outside
+    assert!(!HInt::one().is_lattice_member());
+    assert!(!HInt::i().is_lattice_member());
+
+    let integer = OInt::new(1, 1, 0, 0, 0, 0, 0, 0);
+    let half = OInt::from_halves(1, -1, 1, 1, 1, 1, -1, 1).unwrap();
+    let odd_sum = OInt::new(1, 0, 0, 0, 0, 0, 0, 0);
+    let half_odd_sum = OInt::from_halves(1, 1, 1, 1, 1, 1, 1, -1).unwrap();
+    let mixed = OInt::from_raw(1, 2, 0, 0, 0, 0, 0, 1);
+    for (o, member) in [(integer, true), (half, true), (odd_sum, false), (half_odd_sum, false), (mixed, false)] {
+        assert_eq!(o.is_lattice_member(), member);
+        assert_eq!(o.is_lattice_member(), o.is_valid_e8_element());
+        assert_eq!(o.is_lattice_member(), OInt::is_in_lattice(o.to_lattice_vector()));
+    }
+
+    // Every unit is in the ring, but one() and the mixed-parity units are not
+    // members of the even-sum model
+    assert!(!OInt::one().is_lattice_member() && OInt::one().is_integral_octonion());
+    let quad_unit = OInt::from_raw(1, 1, 1, 1, 0, 0, 0, 0);
+    assert!(!quad_unit.is_lattice_member() && quad_unit.is_integral_octonion());
+}
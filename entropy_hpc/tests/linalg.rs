@@ -0,0 +1,115 @@
+#![cfg(feature = "std")]
+
+use entropy_hpc::linalg::Mat;
+use entropy_hpc::types::cint::{CIFraction, CIntError};
+use entropy_hpc::{CInt, HInt, OInt};
+
+fn c(a: i32, b: i32) -> CInt {
+    CInt::new(a, b)
+}
+
+#[test]
+fn test_gaussian_2x2_product() {
+    let a = Mat::from_rows(vec![vec![c(1, 1), c(2, 0)], vec![c(0, -1), c(3, 2)]]);
+    let b = Mat::from_rows(vec![vec![c(2, 0), c(0, 1)], vec![c(1, -1), c(4, 0)]]);
+
+    // Entries worked out by hand, e.g. (1+i)*2 + 2*(1-i) = 4
+    let expected = Mat::from_rows(vec![
+        vec![c(4, 0), c(7, 1)],
+        vec![c(5, -3), c(13, 8)],
+    ]);
+    assert_eq!(&a * &b, expected);
+    assert_eq!(&a + &b, Mat::from_rows(vec![vec![c(3, 1), c(2, 1)], vec![c(1, -2), c(7, 2)]]));
+    assert_eq!(a.transpose().transpose(), a);
+}
+
+#[test]
+fn test_identity_matrix() {
+    let a = Mat::from_rows(vec![vec![c(1, 2), c(3, 4), c(5, 6)], vec![c(-1, 0), c(0, -1), c(7, 7)]]);
+    assert_eq!(&Mat::identity(2) * &a, a);
+    assert_eq!(&a * &Mat::identity(3), a);
+    assert_eq!(a.rows(), 2);
+    assert_eq!(a.cols(), 3);
+
+    let q = Mat::from_rows(vec![vec![HInt::i(), HInt::j()], vec![HInt::k(), HInt::one()]]);
+    assert_eq!(&Mat::identity(2) * &q, q);
+    assert_eq!(&q * &Mat::identity(2), q);
+
+    let o = Mat::from_rows(vec![vec![OInt::e1(), OInt::e4()], vec![OInt::e7(), OInt::e2()]]);
+    assert_eq!(o.clone() * Mat::identity(2), o);
+}
+
+#[test]
+fn test_noncommutative_entries() {
+    // [i][j] * [j][i]: the single entry is i*j = k, whereas j*i = -k
+    let a = Mat::from_rows(vec![vec![HInt::i()]]);
+    let b = Mat::from_rows(vec![vec![HInt::j()]]);
+    assert_eq!((&a * &b)[(0, 0)], HInt::k());
+    assert_eq!((&b * &a)[(0, 0)], -HInt::k());
+}
+
+#[test]
+fn test_determinant_and_adjugate() {
+    let m2 = Mat::from_rows(vec![vec![c(1, 1), c(2, 0)], vec![c(0, -1), c(3, 2)]]);
+    // (1+i)(3+2i) - 2(-i) = 1 + 5i + 2i
+    assert_eq!(m2.det(), c(1, 7));
+
+    let m3 = Mat::from_rows(vec![
+        vec![c(2, 0), c(0, 1), c(1, 0)],
+        vec![c(1, -1), c(3, 0), c(0, 0)],
+        vec![c(0, 2), c(1, 1), c(4, 0)],
+    ]);
+    // 2(12 - 0) - i(4 - 4i - 0) + 1((1-i)(1+i) - 3(2i)) = 24 - 4i - 4 + 2 - 6i
+    assert_eq!(m3.det(), c(22, -10));
+
+    for m in [&m2, &m3] {
+        let n = m.rows();
+        let det_i = Mat::identity(n).scale(m.det());
+        assert_eq!(m * &m.adjugate(), det_i);
+        assert_eq!(&m.adjugate() * m, det_i);
+    }
+}
+
+#[test]
+fn test_inverse_fraction() {
+    let m = Mat::from_rows(vec![vec![c(2, 0), c(1, 0)], vec![c(1, 0), c(1, 0)]]);
+    assert_eq!(m.det(), CInt::one());
+    let inv = m.inverse_fraction().unwrap();
+    assert_eq!(inv[(0, 0)], CIFraction { num: c(1, 0), den: 1 });
+    assert_eq!(inv[(0, 1)], CIFraction { num: c(-1, 0), den: 1 });
+    assert_eq!(inv[(1, 1)], CIFraction { num: c(2, 0), den: 1 });
+
+    let m = Mat::from_rows(vec![vec![c(2, 0), c(0, 0)], vec![c(0, 0), c(0, 2)]]);
+    let inv = m.inverse_fraction().unwrap();
+    assert_eq!(inv[(0, 0)], CIFraction { num: c(1, 0), den: 2 });
+    assert_eq!(inv[(1, 1)], CIFraction { num: c(0, -1), den: 2 });
+
+    let singular = Mat::from_rows(vec![vec![c(1, 1), c(2, 2)], vec![c(1, 0), c(2, 0)]]);
+    assert_eq!(singular.inverse_fraction(), Err(CIntError::NoInverse));
+}
+
+#[test]
+fn test_adjoint_and_hermitian() {
+    let m = Mat::from_rows(vec![
+        vec![c(1, 2), c(0, -1), c(3, 0)],
+        vec![c(-4, 1), c(2, 2), c(0, 5)],
+    ]);
+    let adj = m.adjoint();
+    assert_eq!((adj.rows(), adj.cols()), (3, 2));
+    assert_eq!(adj[(0, 1)], c(-4, -1));
+    assert_eq!(adj.adjoint(), m);
+    assert!(!m.is_hermitian());
+
+    // (AB)* = B*A*
+    let sq = Mat::from_rows(vec![vec![c(1, 1), c(2, 0)], vec![c(0, 3), c(-1, 0)]]);
+    assert_eq!((&sq * &m).adjoint(), &m.adjoint() * &sq.adjoint());
+
+    // Real diagonal, conjugate-symmetric off the diagonal; M*M is always Hermitian
+    let h = Mat::from_rows(vec![
+        vec![c(2, 0), c(1, -3)],
+        vec![c(1, 3), c(-5, 0)],
+    ]);
+    assert!(h.is_hermitian());
+    assert!((&m.adjoint() * &m).is_hermitian());
+    assert!(!Mat::from_rows(vec![vec![c(1, 1)]]).is_hermitian());
+}
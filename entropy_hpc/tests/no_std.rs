@@ -0,0 +1,30 @@
+// Compile check for the integer core: this crate links no std itself, so
+// everything used here must stay available with `--no-default-features`
+#![no_std]
+
+use entropy_hpc::{CInt, EInt, HInt, OInt};
+
+#[test]
+fn test_core_ops_without_std() {
+    let z = CInt::new(3, 4);
+    assert_eq!(z * z.conj(), CInt::new(25, 0));
+    assert_eq!(z + CInt::i() - CInt::i(), z);
+    assert_eq!((-z).norm_squared(), 25);
+
+    let w = EInt::new(2, 3);
+    assert_eq!(w * w.conj(), EInt::new(7, 0));
+
+    let q = HInt::new(1, 2, 3, 4);
+    assert_eq!(q * q.conj(), HInt::new(30, 0, 0, 0));
+    assert_eq!(q - q, HInt::zero());
+
+    let d = HInt::new(1, 1, 0, 0);
+    let (quot, rem) = q.div_rem_int(d).unwrap();
+    assert_eq!(quot * d + rem, q);
+    assert!(rem.norm_squared() < d.norm_squared());
+
+    let o = OInt::new(1, 2, 3, 4, 5, 6, 7, 8);
+    assert_eq!(o * o.conj(), OInt::new(204, 0, 0, 0, 0, 0, 0, 0));
+    assert_eq!((o + OInt::e7()).norm_squared(), 204 + 17);
+    assert!(OInt::moufang_identity(o, OInt::e1(), OInt::e4()));
+}
@@ -0,0 +1,417 @@
+use entropy_hpc::{CInt, OInt};
+use entropy_hpc::types::oint::{MulStrategy, OIntError};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+fn random_oint(rng: &mut ChaCha8Rng) -> OInt {
+    let parity = rng.gen_range(0..2);
+    let mut c = [0i32; 8];
+    for x in c.iter_mut() {
+        *x = rng.gen_range(-20..20) * 2 + parity;
+    }
+    OInt::from_halves(c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]).unwrap()
+}
+
+#[test]
+fn test_mul_strategies_agree() {
+    let mut rng = ChaCha8Rng::seed_from_u64(7);
+    for _ in 0..1000 {
+        let x = random_oint(&mut rng);
+        let y = random_oint(&mut rng);
+        assert_eq!(x.mul_table(y), x.mul_cd(y));
+    }
+
+    let basis = [OInt::one(), OInt::e1(), OInt::e2(), OInt::e3(),
+                 OInt::e4(), OInt::e5(), OInt::e6(), OInt::e7()];
+    for &x in &basis {
+        for &y in &basis {
+            assert_eq!(x.mul_table(y), x.mul_cd(y));
+        }
+    }
+}
+
+#[test]
+fn test_mul_strategy_dispatch() {
+    let x = OInt::new(1, 2, 0, -1, 3, 0, 1, 0);
+    let y = OInt::new(0, 1, 1, 0, -2, 1, 0, 4);
+
+    assert_eq!(x.mul_with(y, MulStrategy::Table), x.mul_table(y));
+    assert_eq!(x.mul_with(y, MulStrategy::CayleyDickson), x.mul_cd(y));
+    assert_eq!(x.mul_with(y, MulStrategy::CayleyDickson), x * y);
+}
+
+#[test]
+fn test_minimal_polynomial() {
+    let mut rng = ChaCha8Rng::seed_from_u64(11);
+    for _ in 0..500 {
+        let x = random_oint(&mut rng);
+        assert_eq!(x.satisfies_minimal_polynomial(), Ok(true));
+
+        let t = OInt::new(x.trace() as i32, 0, 0, 0, 0, 0, 0, 0);
+        let n = OInt::new(x.reduced_norm() as i32, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq!(x * x, t * x - n);
+        assert_eq!(x * x.conj(), n);
+    }
+
+    // n(x) = 2 * 40000² is past i32, so there is no octonion to compare with
+    let big = OInt::new(40_000, 40_000, 0, 0, 0, 0, 0, 0);
+    assert_eq!(big.satisfies_minimal_polynomial(), Err(OIntError::Overflow));
+}
+
+#[test]
+fn test_raw_round_trip() {
+    let o = OInt::from_halves(1, 1, 1, 1, -1, 1, 1, 3).unwrap();
+    let r = o.raw_components();
+    assert_eq!(r, [1, 1, 1, 1, -1, 1, 1, 3]);
+    assert_eq!(OInt::from_raw(r[0], r[1], r[2], r[3], r[4], r[5], r[6], r[7]), o);
+    assert_eq!(OInt::e7().raw_components(), [0, 0, 0, 0, 0, 0, 0, 2]);
+}
+
+fn basis(i: usize) -> OInt {
+    [OInt::one(), OInt::e1(), OInt::e2(), OInt::e3(),
+     OInt::e4(), OInt::e5(), OInt::e6(), OInt::e7()][i]
+}
+
+#[test]
+fn test_basis_product_matches_mul() {
+    for i in 0..8 {
+        for j in 0..8 {
+            let (sign, k) = OInt::basis_product(i, j);
+            let expected = if sign > 0 { basis(k) } else { -basis(k) };
+            assert_eq!(basis(i) * basis(j), expected, "e{} * e{}", i, j);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_basis_product_rejects_bad_index() {
+    OInt::basis_product(8, 1);
+}
+
+#[test]
+fn test_associator_and_commutator() {
+    let scalar = OInt::new(3, 0, 0, 0, 0, 0, 0, 0);
+    let x = OInt::new(1, 2, -1, 0, 3, 1, 0, -2);
+    let y = OInt::new(0, 1, 1, -1, 0, 2, 1, 1);
+    assert!(OInt::associator(scalar, x, y).is_zero());
+    assert!(OInt::associator(x, scalar, y).is_zero());
+    assert!(OInt::associator(x, y, scalar).is_zero());
+    assert!(OInt::commutator(scalar, x).is_zero());
+
+    let assoc = OInt::associator(OInt::e1(), OInt::e2(), OInt::e4());
+    assert!(!assoc.is_zero());
+    assert_eq!(assoc, OInt::new(0, 0, 0, 0, 0, 0, 0, 2));
+
+    // e1, e2, e3 span a quaternion subalgebra
+    assert!(OInt::associator(OInt::e1(), OInt::e2(), OInt::e3()).is_zero());
+    assert_eq!(OInt::commutator(OInt::e1(), OInt::e2()), OInt::new(0, 0, 0, 2, 0, 0, 0, 0));
+}
+
+#[test]
+fn test_fano_table_antisymmetric_and_complete() {
+    for i in 1..8 {
+        assert_eq!(OInt::basis_product(i, i), (-1, 0));
+        assert_eq!(OInt::basis_product(0, i), (1, i));
+        assert_eq!(OInt::basis_product(i, 0), (1, i));
+        for j in 1..8 {
+            if i == j {
+                continue;
+            }
+            let (s_ij, k_ij) = OInt::basis_product(i, j);
+            let (s_ji, k_ji) = OInt::basis_product(j, i);
+            assert_eq!(k_ij, k_ji);
+            assert_eq!(s_ij, -s_ji);
+            assert!(k_ij != 0 && k_ij != i && k_ij != j);
+        }
+    }
+}
+
+#[test]
+fn test_fano_lines_satisfy_octonion_identities() {
+    let lines = [(1, 2, 3), (1, 4, 5), (1, 7, 6), (2, 4, 6), (2, 5, 7), (3, 4, 7), (3, 6, 5)];
+    for &(i, j, k) in &lines {
+        // Each oriented line is a quaternion triple: e_i e_j = e_k and cyclic
+        assert_eq!(OInt::basis_product(i, j), (1, k));
+        assert_eq!(OInt::basis_product(j, k), (1, i));
+        assert_eq!(OInt::basis_product(k, i), (1, j));
+        assert!(OInt::associator(basis(i), basis(j), basis(k)).is_zero());
+    }
+
+    for i in 0..8 {
+        for j in 0..8 {
+            assert!(OInt::alternative_identity(basis(i), basis(j)));
+            for k in 0..8 {
+                assert!(OInt::moufang_identity(basis(i), basis(j), basis(k)));
+            }
+        }
+    }
+}
+
+const UNITS: [OInt; 8] = [
+    OInt::one(),
+    OInt::e1(),
+    OInt::e2(),
+    OInt::e3(),
+    OInt::e4(),
+    OInt::e5(),
+    OInt::e6(),
+    OInt::e7(),
+];
+
+#[test]
+fn test_const_basis_table() {
+    for (i, &u) in UNITS.iter().enumerate() {
+        assert_eq!(u, basis(i));
+        assert!(u.is_unit());
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_div_rem_int_matches_float_path() {
+    let mut rng = ChaCha8Rng::seed_from_u64(24);
+    for _ in 0..1000 {
+        let a = random_oint(&mut rng);
+        let d = random_oint(&mut rng);
+        if d.is_zero() {
+            continue;
+        }
+        assert_eq!(a.div_rem_int(d).unwrap(), a.div_rem(d).unwrap());
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_div_rem_int_large_numerator() {
+    // The stored real part of a * conj(d) is 3e9, which does not fit in i32
+    let a = OInt::new(50_000_000, 0, 0, 0, 0, 0, 0, 0);
+    let d = OInt::new(30, 0, 0, 0, 10, 0, 0, 0);
+
+    let (q, r) = a.div_rem_int(d).unwrap();
+    assert_eq!(q, OInt::new(1_500_000, 0, 0, 0, -500_000, 0, 0, 0));
+    assert!(r.is_zero());
+
+    assert_eq!(a.div_rem(d).unwrap(), (q, r));
+}
+
+#[test]
+fn test_norm_multiplicative() {
+    let mut rng = ChaCha8Rng::seed_from_u64(32);
+    for _ in 0..1000 {
+        let a = random_oint(&mut rng);
+        let b = random_oint(&mut rng);
+        assert!(OInt::check_norm_multiplicative(a, b));
+    }
+
+    // Sums of basis elements exercise the cross terms e_i * e_j of the table
+    let x = OInt::e1() + OInt::e2();
+    let y = OInt::e1() + OInt::e4();
+    assert!(OInt::check_norm_multiplicative(x, y));
+    assert_eq!((x * y).norm_squared(), 4);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_no_zero_divisors() {
+    let mut rng = ChaCha8Rng::seed_from_u64(33);
+    let samples: Vec<(OInt, OInt)> = (0..1000)
+        .map(|_| (random_oint(&mut rng), random_oint(&mut rng)))
+        .collect();
+    assert!(OInt::norm_is_multiplicative_over(&samples));
+    assert!(samples.iter().all(|&(a, b)| !OInt::has_zero_divisor_witness(a, b)));
+
+    // All pairs of the 240 units, which exercise every entry of the table
+    let units = OInt::units();
+    assert!(units.iter().all(|&u| units.iter().all(|&v| !OInt::has_zero_divisor_witness(u, v))));
+
+    assert!(!OInt::has_zero_divisor_witness(OInt::zero(), OInt::e1()));
+    let big = OInt::new(1 << 20, 0, 0, 0, 0, 0, 0, 0);
+    assert!(!OInt::has_zero_divisor_witness(big, big));
+}
+
+#[test]
+fn test_from_e8() {
+    use entropy_hpc::types::oint::OIntError;
+
+    let x = OInt::from_e8([1, -1, 1, 1, 1, 1, -1, 1]).unwrap();
+    assert_eq!(x, OInt::from_halves(1, -1, 1, 1, 1, 1, -1, 1).unwrap());
+    assert_eq!(x.lattice_norm_squared(), 2);
+    assert!(OInt::from_e8([2, 2, 0, 0, 0, 0, 0, 0]).is_ok());
+
+    // Half-integer with odd coordinate sum: accepted by from_halves, not in E₈
+    assert_eq!(OInt::from_e8([1, 1, 1, 1, 1, 1, 1, -1]), Err(OIntError::NotInLattice));
+    assert!(OInt::from_halves(1, 1, 1, 1, 1, 1, 1, -1).is_ok());
+    assert_eq!(OInt::from_e8([2, 0, 0, 0, 0, 0, 0, 0]), Err(OIntError::NotInLattice));
+    assert_eq!(OInt::from_e8([1, 2, 0, 0, 0, 0, 0, 1]), Err(OIntError::InvalidHalfInteger));
+
+    // Agrees with the rest of the lattice API on one()
+    let one = OInt::one();
+    assert!(OInt::from_e8(one.raw_components()).is_err());
+    assert!(one.e8_coset().is_none() && !one.is_lattice_member());
+}
+
+#[test]
+fn test_quaternion_pair_matches_cayley_dickson() {
+    use entropy_hpc::HInt;
+
+    let x = OInt::new(1, 2, 3, 4, 5, 6, 7, 8);
+    let (p, q) = x.to_quaternion_pair();
+    assert_eq!(p, HInt::new(1, 2, 3, 4));
+    assert_eq!(q, HInt::new(5, 6, 7, 8));
+    assert_eq!(OInt::from_quaternion_pair(p, q), x);
+    assert_eq!(OInt::from_quaternion_pair(HInt::zero(), HInt::one()), OInt::e4());
+
+    // (a, b)(c, d) = (ac - d̄b, da + bc̄) evaluated in HInt arithmetic
+    let mut rng = ChaCha8Rng::seed_from_u64(49);
+    for _ in 0..1000 {
+        let x = random_oint(&mut rng);
+        let y = random_oint(&mut rng);
+        let (a, b) = x.to_quaternion_pair();
+        let (c, d) = y.to_quaternion_pair();
+        let expected = OInt::from_quaternion_pair(a * c - d.conj() * b, d * a + b * c.conj());
+        assert_eq!(x.mul_table(y), expected);
+        assert_eq!(x * y, expected);
+    }
+}
+
+#[test]
+fn test_flexible_identity() {
+    let mut rng = ChaCha8Rng::seed_from_u64(60);
+    for _ in 0..1000 {
+        let a = random_oint(&mut rng);
+        let b = random_oint(&mut rng);
+        assert!(OInt::flexible_identity(a, b));
+    }
+
+    // Holds even on a triple where associativity fails
+    let (a, b, c) = (OInt::e1(), OInt::e2(), OInt::e4());
+    assert_ne!((a * b) * c, a * (b * c));
+    assert!(OInt::flexible_identity(a, b));
+    assert!(OInt::flexible_identity(a + c, b));
+
+    // Scalars commute and associate with everything
+    let x = OInt::new(1, -2, 0, 3, 0, 1, 0, -1);
+    for s in [OInt::zero(), OInt::one(), OInt::new(-3, 0, 0, 0, 0, 0, 0, 0)] {
+        assert!(OInt::flexible_identity(s, x));
+        assert!(OInt::flexible_identity(x, s));
+    }
+}
+
+#[test]
+fn test_associator_norm_stats() {
+    let s = OInt::new(3, 0, 0, 0, 0, 0, 0, 0);
+    let scalars = [(s, OInt::one(), s), (OInt::zero(), s, s)];
+    assert_eq!(OInt::associator_norm_stats(&scalars), (0.0, 2));
+
+    // e1, e2, e4 lie on no common Fano line: [e1,e2,e4] = 2e7, norm 4.
+    // e1, e2, e3 span a quaternion subalgebra, which associates.
+    let mixed = [(OInt::e1(), OInt::e2(), OInt::e4()), (OInt::e1(), OInt::e2(), OInt::e3())];
+    assert_eq!(OInt::associator_norm_stats(&mixed), (2.0, 1));
+
+    assert_eq!(OInt::associator_norm_stats(&[]), (0.0, 0));
+}
+
+#[test]
+fn test_integer_and_half_integer_predicates() {
+    let x = OInt::new(1, 0, 0, 0, 0, 0, 0, 0);
+    assert!(x.is_integer() && !x.is_half_integer());
+    // Coordinate sum 1 is odd, so 1 itself is not an E₈ point in this convention
+    assert!(!x.is_valid_e8_element());
+    assert!(OInt::new(1, 1, 0, 0, 0, 0, 0, 0).is_valid_e8_element());
+
+    let h = OInt::from_halves(1, 1, 1, 1, 1, 1, 1, 1).unwrap();
+    assert!(h.is_half_integer() && !h.is_integer());
+    assert!(h.is_valid_e8_element());
+    assert!(!OInt::from_halves(1, 1, 1, 1, 1, 1, 1, -1).unwrap().is_valid_e8_element());
+
+    // Mixed parity is neither
+    let mixed = OInt::from_raw(1, 2, 0, 0, 0, 0, 0, 1);
+    assert!(!mixed.is_integer() && !mixed.is_half_integer() && !mixed.is_valid_e8_element());
+    assert!(OInt::zero().is_integer() && OInt::zero().is_valid_e8_element());
+
+    // Same answer as is_in_lattice on the stored vector
+    for o in [x, h, mixed, OInt::from_raw(1, -1, 1, 1, 2, 0, 0, 0)] {
+        assert_eq!(o.is_valid_e8_element(), OInt::is_in_lattice(o.to_lattice_vector()));
+    }
+}
+
+#[test]
+fn test_is_integral_octonion() {
+    // The ring contains 1 and every e_i, whatever their coordinate sum
+    assert!(OInt::one().is_integral_octonion() && OInt::e5().is_integral_octonion());
+    assert!(OInt::from_halves(1, 1, 1, 1, 1, 1, 1, -1).unwrap().is_integral_octonion());
+
+    // Mixed parity is integral only on a unit quadruple
+    assert!(OInt::from_raw(1, -1, 1, 1, 2, 0, 0, 0).is_integral_octonion());
+    assert!(!OInt::from_raw(1, 2, 0, 0, 0, 0, 0, 1).is_integral_octonion());
+    assert!(!OInt::from_raw(1, 1, 1, 0, 0, 0, 0, 0).is_integral_octonion());
+
+    // Units and their products stay in the ring
+    let units: Vec<OInt> = (0..OInt::UNIT_COUNT).filter_map(OInt::unit).collect();
+    assert!(units.iter().all(|u| u.is_integral_octonion()));
+    for &u in &units {
+        for &v in units.iter().step_by(7) {
+            assert!((u * v).is_integral_octonion());
+        }
+    }
+}
+
+#[test]
+fn test_from_halves_strict() {
+    // Halves summing to 8 (coordinate sum 4) are an E₈ point
+    let valid = OInt::from_halves_strict(1, 1, 1, 1, 1, 1, 1, 1).unwrap();
+    assert_eq!(valid, OInt::from_halves(1, 1, 1, 1, 1, 1, 1, 1).unwrap());
+    assert!(OInt::from_halves_strict(2, 2, 0, 0, 0, 0, 0, 0).is_ok());
+
+    // All odd but halves summing to 6: from_halves accepts it, strict does not
+    assert!(OInt::from_halves(1, 1, 1, 1, 1, 1, 1, -1).is_ok());
+    assert_eq!(OInt::from_halves_strict(1, 1, 1, 1, 1, 1, 1, -1), Err(OIntError::InvalidHalfInteger));
+    assert_eq!(OInt::from_halves_strict(2, 0, 0, 0, 0, 0, 0, 0), Err(OIntError::InvalidHalfInteger));
+
+    // Never looser than from_halves: mixed parity fails both
+    assert_eq!(OInt::from_halves_strict(1, 2, 1, 1, 1, 1, 1, 1), Err(OIntError::InvalidHalfInteger));
+    for u in (0..OInt::UNIT_COUNT).filter_map(OInt::unit) {
+        let [a, b, c, d, e, f, g, h] = u.raw_components();
+        if OInt::from_halves(a, b, c, d, e, f, g, h).is_err() {
+            assert_eq!(OInt::from_halves_strict(a, b, c, d, e, f, g, h), Err(OIntError::InvalidHalfInteger));
+        }
+    }
+}
+
+#[test]
+fn test_double_and_try_halve() {
+    let mut rng = ChaCha8Rng::seed_from_u64(81);
+    for _ in 0..200 {
+        let x = random_oint(&mut rng);
+        assert_eq!(x.double(), x + x);
+        assert_eq!(x.double().try_halve(), Some(x));
+    }
+
+    let half = OInt::from_halves(1, 1, 1, 1, 1, 1, 1, 1).unwrap();
+    assert_eq!(half.try_halve(), None);
+    assert_eq!(OInt::new(2, 0, 0, 0, 0, 0, 0, 0).try_halve(), Some(OInt::one()));
+    // 1/2 alone is not a valid octonion value: mixed parity halves
+    assert_eq!(OInt::one().try_halve(), None);
+    assert_eq!(OInt::new(1, 1, 1, 1, 1, 1, 1, 1).try_halve(), Some(half));
+
+    // Every unit halves back, the mixed-parity ones included
+    for u in (0..OInt::UNIT_COUNT).filter_map(OInt::unit) {
+        assert_eq!(u.double().try_halve(), Some(u));
+    }
+}
+
+#[test]
+fn test_complex_projections_round_trip() {
+    let mut rng = ChaCha8Rng::seed_from_u64(83);
+    for _ in 0..100 {
+        let x = random_oint(&mut rng);
+        assert_eq!(OInt::from_complex_projections(x.complex_projections()), x);
+    }
+
+    // Doubled coordinates, plane by plane
+    let x = OInt::new(1, 2, 0, -1, 3, 0, 0, 4);
+    assert_eq!(x.complex_projections(), [CInt::new(2, 4), CInt::new(0, -2), CInt::new(6, 0), CInt::new(0, 8)]);
+    let h = OInt::from_halves(1, 1, 1, 1, 1, 1, 1, -1).unwrap();
+    assert_eq!(h.complex_projections()[3], CInt::new(1, -1));
+}
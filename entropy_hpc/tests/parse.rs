@@ -0,0 +1,41 @@
+#![cfg(feature = "std")]
+
+use entropy_hpc::{CInt, HInt, OInt};
+use entropy_hpc::types::cint::CIntError;
+use entropy_hpc::types::oint::OIntError;
+
+#[test]
+fn test_coords_str_integer_round_trip() {
+    let o = OInt::from_coords_str("1,1,1,1,0,0,0,0").unwrap();
+    assert_eq!(o, OInt::new(1, 1, 1, 1, 0, 0, 0, 0));
+    assert_eq!(OInt::from_coords_str(&o.to_coords_str()).unwrap(), o);
+
+    let h = HInt::from_coords_str("1, -2, 3, 0").unwrap();
+    assert_eq!(h, HInt::new(1, -2, 3, 0));
+    assert_eq!(h.to_coords_str(), "1,-2,3,0");
+
+    let z = CInt::from_coords_str("3,-4").unwrap();
+    assert_eq!(z, CInt::new(3, -4));
+    assert_eq!(CInt::from_coords_str(&z.to_coords_str()).unwrap(), z);
+}
+
+#[test]
+fn test_coords_str_half_integer_round_trip() {
+    let o = OInt::from_coords_str("0.5,0.5,0.5,0.5,0.5,0.5,-0.5,-1.5").unwrap();
+    assert_eq!(o, OInt::from_halves(1, 1, 1, 1, 1, 1, -1, -3).unwrap());
+    assert_eq!(o.to_coords_str(), "0.5,0.5,0.5,0.5,0.5,0.5,-0.5,-1.5");
+    assert_eq!(OInt::from_coords_str(&o.to_coords_str()).unwrap(), o);
+
+    let h = HInt::from_coords_str("0.5,0.5,0.5,0.5").unwrap();
+    assert_eq!(h, HInt::from_halves(1, 1, 1, 1).unwrap());
+    assert_eq!(HInt::from_coords_str(&h.to_coords_str()).unwrap(), h);
+}
+
+#[test]
+fn test_coords_str_errors() {
+    assert_eq!(OInt::from_coords_str("1,2,3"), Err(OIntError::InvalidFormat));
+    assert_eq!(OInt::from_coords_str("1,x,0,0,0,0,0,0"), Err(OIntError::InvalidFormat));
+    assert_eq!(OInt::from_coords_str("0.25,0,0,0,0,0,0,0"), Err(OIntError::InvalidFormat));
+    assert_eq!(OInt::from_coords_str("0.5,0,0,0,0,0,0,0"), Err(OIntError::InvalidHalfInteger));
+    assert_eq!(CInt::from_coords_str("0.5,1"), Err(CIntError::InvalidFormat));
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "std")]
+
+use entropy_hpc::poly::Poly;
+use entropy_hpc::CInt;
+
+fn p(coeffs: &[(i32, i32)]) -> Poly<CInt> {
+    Poly::new(coeffs.iter().map(|&(a, b)| CInt::new(a, b)).collect())
+}
+
+#[test]
+fn test_poly_multiplication() {
+    // (x - 1)(x + 1) = x² - 1
+    assert_eq!(p(&[(-1, 0), (1, 0)]) * p(&[(1, 0), (1, 0)]), p(&[(-1, 0), (0, 0), (1, 0)]));
+
+    // (1 + ix)(2 - x) = 2 + (2i - 1)x - ix²
+    assert_eq!(p(&[(1, 0), (0, 1)]) * p(&[(2, 0), (-1, 0)]), p(&[(2, 0), (-1, 2), (0, -1)]));
+
+    let f = p(&[(3, -1), (0, 2), (1, 1)]);
+    assert_eq!(&f * &Poly::zero(), Poly::zero());
+    assert_eq!(&f - &f, Poly::zero());
+    assert_eq!((&f * &Poly::x()).degree(), Some(3));
+    assert_eq!(f.eval(CInt::i()), CInt::new(3, -1) + CInt::new(0, 2) * CInt::i() + CInt::new(1, 1) * CInt::new(-1, 0));
+}
+
+#[test]
+fn test_poly_div_rem() {
+    let x2_minus_1 = p(&[(-1, 0), (0, 0), (1, 0)]);
+    let (q, r, s) = x2_minus_1.div_rem(&p(&[(-1, 0), (1, 0)])).unwrap();
+    assert_eq!((q, r, s), (p(&[(1, 0), (1, 0)]), Poly::zero(), CInt::one()));
+
+    // Division by 2x needs a scaling factor: 2(x² - 1) = x * 2x - 2
+    let (q, r, s) = x2_minus_1.div_rem(&p(&[(0, 0), (2, 0)])).unwrap();
+    assert_eq!(x2_minus_1.scale(s), &(&q * &p(&[(0, 0), (2, 0)])) + &r);
+    assert_eq!(r.degree(), Some(0));
+
+    assert!(x2_minus_1.div_rem(&Poly::zero()).is_err());
+}
+
+#[test]
+fn test_poly_gcd_with_derivative() {
+    let x_minus_1 = p(&[(-1, 0), (1, 0)]);
+    let x_plus_1 = p(&[(1, 0), (1, 0)]);
+
+    // x² - 1 is square-free: coprime to its derivative 2x
+    let f = &x_minus_1 * &x_plus_1;
+    assert_eq!(f.derivative(), p(&[(0, 0), (2, 0)]));
+    assert_eq!(Poly::gcd(&f, &f.derivative()), Poly::constant(CInt::one()));
+
+    // (x - 1)²(x + 1) shares the repeated factor with its derivative
+    let g = &(&x_minus_1 * &x_minus_1) * &x_plus_1;
+    assert_eq!(Poly::gcd(&g, &g.derivative()), x_minus_1);
+
+    // gcd(x² - 1, (x + 1)(x + i)) = x + 1, independent of scalar factors
+    let h = &x_plus_1 * &p(&[(0, 1), (1, 0)]);
+    assert_eq!(Poly::gcd(&f.scale(CInt::new(2, 1)), &h), x_plus_1);
+}
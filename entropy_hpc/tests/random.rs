@@ -0,0 +1,53 @@
+#![cfg(feature = "rand")]
+
+use entropy_hpc::{CInt, HInt, OInt};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashSet;
+
+#[test]
+fn test_random_units_have_norm_one() {
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    let mut seen = HashSet::new();
+    for _ in 0..1000 {
+        assert_eq!(CInt::random_unit(&mut rng).norm_squared(), 1);
+        assert_eq!(HInt::random_unit(&mut rng).norm_squared(), 1);
+        let u = OInt::random_unit(&mut rng);
+        assert_eq!(u.norm_squared(), 1);
+        seen.insert(u);
+    }
+    assert!(seen.len() > 200);
+}
+
+#[test]
+fn test_random_values_have_uniform_parity() {
+    let mut rng = ChaCha8Rng::seed_from_u64(2);
+    for _ in 0..1000 {
+        let h: HInt = rng.gen();
+        let r = h.raw_components();
+        assert!(HInt::from_halves(r[0], r[1], r[2], r[3]).is_ok());
+
+        let o: OInt = rng.gen();
+        let r = o.raw_components();
+        assert!(OInt::from_halves(r[0], r[1], r[2], r[3], r[4], r[5], r[6], r[7]).is_ok());
+
+        let z: CInt = rng.gen();
+        assert!(z.a.abs() <= 1000 && z.b.abs() <= 1000);
+    }
+}
+
+#[test]
+fn test_random_minimal_vector_covers_the_roots() {
+    let mut rng = ChaCha8Rng::seed_from_u64(88);
+    let mut seen = HashSet::new();
+    for _ in 0..10_000 {
+        let v = OInt::random_minimal_vector(&mut rng);
+        assert_eq!(v.lattice_norm_squared(), 2);
+        assert!(OInt::is_in_lattice(v.to_lattice_vector()));
+        seen.insert(v);
+    }
+
+    // Expected draws to collect all 240 are about 240 · H(240) ≈ 1450
+    let roots: HashSet<OInt> = OInt::vectors_within(2).into_iter().filter(|v| !v.is_zero()).collect();
+    assert_eq!(seen, roots);
+}
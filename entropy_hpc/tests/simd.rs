@@ -0,0 +1,228 @@
+#![cfg(feature = "std")]
+
+use entropy_hpc::CInt;
+use entropy_hpc::simd_engine;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+#[test]
+fn test_cint_mul_arrays_matches_scalar() {
+    let mut rng = ChaCha8Rng::seed_from_u64(3);
+    let len = 1003;
+    let a: Vec<CInt> = (0..len).map(|_| CInt::new(rng.gen_range(-30000..30000), rng.gen_range(-30000..30000))).collect();
+    let b: Vec<CInt> = (0..len).map(|_| CInt::new(rng.gen_range(-30000..30000), rng.gen_range(-30000..30000))).collect();
+
+    let mut out = vec![CInt::zero(); len];
+    simd_engine::cint_mul_arrays(&a, &b, &mut out);
+
+    for i in 0..len {
+        assert_eq!(out[i], a[i] * b[i]);
+    }
+}
+
+#[test]
+#[should_panic(expected = "CInt multiplication overflow")]
+fn test_cint_mul_batch_overflow_panics() {
+    let a = [CInt::new(i32::MAX, 0); 4];
+    let b = [CInt::new(2, 0); 4];
+    simd_engine::cint_mul_batch(&a, &b);
+}
+
+#[test]
+fn test_z2_dot_product_matches_fold() {
+    use entropy_hpc::simd::LatticeSimd;
+
+    let mut rng = ChaCha8Rng::seed_from_u64(47);
+    for len in [0, 1, 7, 500] {
+        let a: Vec<CInt> = (0..len).map(|_| CInt::new(rng.gen_range(-1000..1000), rng.gen_range(-1000..1000))).collect();
+        let b: Vec<CInt> = (0..len).map(|_| CInt::new(rng.gen_range(-1000..1000), rng.gen_range(-1000..1000))).collect();
+        let naive = a.iter().zip(&b).fold(CInt::zero(), |acc, (&x, &y)| acc + x * y);
+        assert_eq!(LatticeSimd::z2_dot_product(&a, &b), naive);
+    }
+
+    // Each product overflows i32 on its own, but they cancel
+    let a = [CInt::new(i32::MAX, 0), CInt::new(i32::MAX, 0)];
+    let b = [CInt::new(2, 0), CInt::new(-2, 0)];
+    assert_eq!(LatticeSimd::z2_dot_product(&a, &b), CInt::zero());
+
+    // The first two products already sum to 2^63, past i64
+    let m = CInt::new(i32::MIN, 0);
+    let a = [m, m, m, m, m];
+    let b = [m, m, CInt::new(i32::MAX, 0), CInt::new(i32::MAX, 0), CInt::new(2, 0)];
+    assert_eq!(LatticeSimd::z2_dot_product(&a, &b), CInt::zero());
+}
+
+#[test]
+#[should_panic(expected = "CInt dot product overflow")]
+fn test_z2_dot_product_overflow_panics() {
+    use entropy_hpc::simd::LatticeSimd;
+    LatticeSimd::z2_dot_product(&[CInt::new(i32::MAX, 0)], &[CInt::new(2, 0)]);
+}
+
+#[test]
+fn test_total_norm_squared_matches_scalar() {
+    use entropy_hpc::simd::LatticeSimd;
+    use entropy_hpc::{HInt, OInt};
+
+    let mut rng = ChaCha8Rng::seed_from_u64(46);
+    let z2: Vec<CInt> = (0..1001).map(|_| CInt::new(rng.gen_range(-1000..1000), rng.gen_range(-1000..1000))).collect();
+    assert_eq!(LatticeSimd::z2_total_norm_squared(&z2), z2.iter().map(|p| p.norm_squared()).sum::<u64>());
+
+    let d4: Vec<HInt> = (0..1001).map(|_| {
+        let p = rng.gen_range(0..2);
+        let mut c = [0i32; 4];
+        for x in c.iter_mut() {
+            *x = rng.gen_range(-1000..1000) * 2 + p;
+        }
+        HInt::from_halves(c[0], c[1], c[2], c[3]).unwrap()
+    }).collect();
+    assert_eq!(LatticeSimd::d4_total_norm_squared(&d4), d4.iter().map(|p| p.norm_squared()).sum::<u64>());
+
+    // Components of 10⁶ overflow the per-point i32 norm but not the u64 total
+    let e8: Vec<OInt> = (0..100_000).map(|_| {
+        let p = rng.gen_range(0..2);
+        let mut c = [0i32; 8];
+        for x in c.iter_mut() {
+            *x = rng.gen_range(-500_000..500_000) * 2 + p;
+        }
+        OInt::from_halves(c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]).unwrap()
+    }).collect();
+    assert_eq!(LatticeSimd::e8_total_norm_squared(&e8), e8.iter().map(|p| p.norm_squared()).sum::<u64>());
+    assert_eq!(LatticeSimd::e8_total_norm_squared(&[]), 0);
+}
+
+#[test]
+fn test_conj_batch_matches_scalar() {
+    use entropy_hpc::simd::LatticeSimd;
+    use entropy_hpc::{HInt, OInt};
+
+    let mut rng = ChaCha8Rng::seed_from_u64(50);
+    let mut r = || rng.gen_range(-1000..1000);
+
+    // Lengths that leave a partial register at the end
+    let z2: Vec<CInt> = (0..13).map(|_| CInt::new(r(), r())).collect();
+    let expected: Vec<CInt> = z2.iter().map(|p| p.conj()).collect();
+    assert_eq!(LatticeSimd::z2_conj_batch(&z2), expected);
+
+    let d4: Vec<HInt> = (0..7).map(|_| HInt::new(r(), r(), r(), r())).collect();
+    let expected: Vec<HInt> = d4.iter().map(|p| p.conj()).collect();
+    assert_eq!(LatticeSimd::d4_conj_batch(&d4), expected);
+
+    let e8: Vec<OInt> = (0..5).map(|_| OInt::new(r(), r(), r(), r(), r(), r(), r(), r())).collect();
+    let expected: Vec<OInt> = e8.iter().map(|p| p.conj()).collect();
+    assert_eq!(LatticeSimd::e8_conj_batch(&e8), expected);
+    assert!(LatticeSimd::e8_conj_batch(&[]).is_empty());
+}
+
+#[test]
+fn test_cint_mul_arrays_short_lengths() {
+    let mut rng = ChaCha8Rng::seed_from_u64(56);
+    for len in 0..10 {
+        let a: Vec<CInt> = (0..len).map(|_| CInt::new(rng.gen_range(-30000..30000), rng.gen_range(-30000..30000))).collect();
+        let b: Vec<CInt> = (0..len).map(|_| CInt::new(rng.gen_range(-30000..30000), rng.gen_range(-30000..30000))).collect();
+
+        let mut out = vec![CInt::zero(); len];
+        simd_engine::cint_mul_arrays(&a, &b, &mut out);
+        let expected: Vec<CInt> = a.iter().zip(&b).map(|(&x, &y)| x * y).collect();
+        assert_eq!(out, expected);
+    }
+
+    let a = [CInt::new(1, 2), CInt::new(-3, 4), CInt::new(0, -1), CInt::new(7, 0)];
+    let b = [CInt::new(5, -6), CInt::new(2, 2), CInt::new(0, -1), CInt::new(-1, 3)];
+    assert_eq!(simd_engine::cint_mul_batch(&a, &b), [a[0] * b[0], a[1] * b[1], a[2] * b[2], a[3] * b[3]]);
+}
+
+#[test]
+fn test_e8_distance_squared_batch_matches_scalar() {
+    use entropy_hpc::simd::LatticeSimd;
+    use entropy_hpc::OInt;
+
+    let mut rng = ChaCha8Rng::seed_from_u64(79);
+    let mut r = || rng.gen_range(-5000..5000);
+
+    let points: Vec<OInt> = (0..257).map(|_| OInt::new(r(), r(), r(), r(), r(), r(), r(), r())).collect();
+    let target = OInt::from_halves(3, -1, 7, 1, -9, 5, 1, 1).unwrap();
+    let expected: Vec<i32> = points.iter().map(|p| p.lattice_distance_squared(target)).collect();
+    assert_eq!(LatticeSimd::e8_distance_squared_batch(&points, target), expected);
+
+    assert_eq!(LatticeSimd::e8_distance_squared_batch(&[target], target), vec![0]);
+    assert!(LatticeSimd::e8_distance_squared_batch(&[], target).is_empty());
+
+    // Stored differences past i32 are still exact as long as the result fits
+    let a = OInt::from_raw(i32::MAX, 0, 0, 0, 0, 0, 0, 0);
+    let b = OInt::from_raw(i32::MAX - 60_000, 0, 0, 0, 0, 0, 0, 0);
+    assert_eq!(LatticeSimd::e8_distance_squared_batch(&[a], b), vec![900_000_000]);
+}
+
+#[test]
+#[should_panic(expected = "E8 distance overflow")]
+fn test_e8_distance_squared_batch_overflow_panics() {
+    use entropy_hpc::simd::LatticeSimd;
+    use entropy_hpc::OInt;
+
+    // (80000² + 80000²) / 4 = 3.2e9 does not fit i32
+    let far = OInt::from_raw(80_000, 80_000, 0, 0, 0, 0, 0, 0);
+    LatticeSimd::e8_distance_squared_batch(&[far], OInt::zero());
+}
+
+#[test]
+#[should_panic(expected = "E8 distance overflow")]
+fn test_e8_distance_squared_batch_wide_difference_panics() {
+    use entropy_hpc::simd::LatticeSimd;
+    use entropy_hpc::OInt;
+
+    // The stored difference itself is past i32
+    let lo = OInt::from_raw(i32::MIN, 0, 0, 0, 0, 0, 0, 0);
+    let hi = OInt::from_raw(i32::MAX, 0, 0, 0, 0, 0, 0, 0);
+    LatticeSimd::e8_distance_squared_batch(&[lo], hi);
+}
+
+#[test]
+fn test_e8_find() {
+    use entropy_hpc::simd::LatticeSimd;
+    use entropy_hpc::OInt;
+
+    let mut rng = ChaCha8Rng::seed_from_u64(96);
+    let mut r = || rng.gen_range(-50..50);
+
+    let mut points: Vec<OInt> = (0..300).map(|_| OInt::new(r(), r(), r(), r(), r(), r(), r(), r())).collect();
+    let needle = OInt::from_halves(3, -1, 7, 1, -9, 5, 1, 1).unwrap();
+    assert_eq!(LatticeSimd::e8_find(&points, needle), None);
+
+    points[211] = needle;
+    points[250] = needle;
+    assert_eq!(LatticeSimd::e8_find(&points, needle), Some(211));
+    assert_eq!(LatticeSimd::e8_find(&points, points[0]), points.iter().position(|&p| p == points[0]));
+
+    // Seven of eight components matching is not a hit
+    let near = OInt::from_halves(3, -1, 7, 1, -9, 5, 1, -1).unwrap();
+    assert_eq!(LatticeSimd::e8_find(&[near], needle), None);
+    assert_eq!(LatticeSimd::e8_find(&[], needle), None);
+}
+
+#[test]
+fn test_e8_snap_batch() {
+    use entropy_hpc::simd::LatticeSimd;
+    use entropy_hpc::OInt;
+
+    // Lattice points, in real coordinates, snap to themselves
+    let on_lattice = [
+        OInt::zero(),
+        OInt::new(1, -1, 0, 0, 0, 0, 0, 0),
+        OInt::from_halves(1, -1, 1, 1, 1, 1, -1, 1).unwrap(),
+        OInt::new(3, 0, -2, 0, 1, 0, 0, 4),
+    ];
+    let targets: Vec<[f64; 8]> = on_lattice.iter().map(|p| p.raw_components().map(|v| v as f64 / 2.0)).collect();
+    for ((p, err), &expected) in LatticeSimd::e8_snap_batch(&targets).into_iter().zip(&on_lattice) {
+        assert_eq!(p, expected);
+        assert_eq!(err, 0.0);
+    }
+
+    let mut rng = ChaCha8Rng::seed_from_u64(99);
+    let targets: Vec<[f64; 8]> = (0..200).map(|_| [0; 8].map(|_: i32| rng.gen_range(-4.0..4.0))).collect();
+    for (t, (p, err)) in targets.iter().zip(LatticeSimd::e8_snap_batch(&targets)) {
+        assert_eq!(p, OInt::closest_lattice_point(*t));
+        assert_eq!(err, OInt::quantization_error_squared(*t));
+        assert!(err <= 1.0 + 1e-9);
+    }
+}